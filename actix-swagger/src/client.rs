@@ -0,0 +1,368 @@
+//! Runtime for generated HTTP clients
+//!
+//! Generated client builds [`ClientRequest`] for every operation and sends it with [`HttpClient`].
+//! Any http layer can be plugged by implementing [`HttpClient`], `awc` is used by default.
+
+pub use crate::error::ClientError;
+pub use actix_http::Method;
+
+use actix_web::{
+    cookie::Cookie,
+    http::{
+        header::{self, HeaderMap, HeaderName, HeaderValue},
+        StatusCode,
+    },
+    web::Bytes,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by http layer
+pub type ClientFuture<T> = Pin<Box<dyn Future<Output = Result<T, ClientError>>>>;
+
+/// Http layer used by generated client to send requests
+pub trait HttpClient {
+    fn send(&self, request: ClientRequest) -> ClientFuture<ClientResponse>;
+}
+
+/// Default http layer of generated client
+#[cfg(feature = "awc")]
+pub type AwcClient = awc::Client;
+
+#[cfg(feature = "awc")]
+impl HttpClient for awc::Client {
+    fn send(&self, request: ClientRequest) -> ClientFuture<ClientResponse> {
+        let ClientRequest {
+            method,
+            url,
+            headers,
+            body,
+        } = request;
+        let mut builder = self.request(method, url);
+
+        for (name, value) in headers {
+            builder = builder.append_header((name, value));
+        }
+
+        Box::pin(async move {
+            let sent = match body {
+                Some(body) => builder.send_body(body).await,
+                None => builder.send().await,
+            };
+            let mut response = sent.map_err(|error| ClientError::Transport(error.to_string()))?;
+            let body = response
+                .body()
+                .await
+                .map_err(|error| ClientError::Transport(error.to_string()))?;
+
+            Ok(ClientResponse {
+                status: response.status(),
+                headers: response.headers().clone(),
+                body,
+            })
+        })
+    }
+}
+
+/// Request prepared by generated client
+#[derive(Debug)]
+pub struct ClientRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+    pub body: Option<Bytes>,
+}
+
+impl ClientRequest {
+    pub fn new(method: Method, url: String) -> Self {
+        ClientRequest {
+            method,
+            url,
+            headers: vec![],
+            body: None,
+        }
+    }
+
+    /// Append url encoded query string, empty query leaves url untouched
+    pub fn query<Q: Serialize>(mut self, query: &Q) -> Result<Self, ClientError> {
+        let encoded = serde_urlencoded::to_string(query)?;
+
+        if !encoded.is_empty() {
+            let separator = if self.url.contains('?') { '&' } else { '?' };
+            self.url = format!("{}{}{}", self.url, separator, encoded);
+        }
+
+        Ok(self)
+    }
+
+    /// Append header with plain value, see [`to_plain`]
+    pub fn header<V: Serialize>(self, name: &'static str, value: &V) -> Result<Self, ClientError> {
+        let value = to_plain(value)?;
+
+        self.raw_header(name, value)
+    }
+
+    /// Send cookies with percent encoded values, actix-web decodes them before [`Cookies`](crate::Cookies) reads them
+    pub fn cookies<C: Serialize>(self, cookies: &C) -> Result<Self, ClientError> {
+        // Serializer flattens struct into plain pairs, the same way `Cookies` deserializes them
+        let encoded = serde_urlencoded::to_string(cookies)?;
+        let pairs: Vec<(String, String)> =
+            serde_urlencoded::from_str(&encoded).map_err(|_| ClientError::NotPlainValue)?;
+
+        if pairs.is_empty() {
+            return Ok(self);
+        }
+
+        let header = pairs
+            .into_iter()
+            .map(|(name, value)| Cookie::new(name, value).encoded().stripped().to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        self.raw_header("Cookie", header)
+    }
+
+    /// Serialize body to json and set content type
+    pub fn json<B: Serialize>(mut self, body: &B) -> Result<Self, ClientError> {
+        self.body = Some(Bytes::from(serde_json::to_vec(body)?));
+        self.headers.push((
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        ));
+
+        Ok(self)
+    }
+
+    fn raw_header(mut self, name: &'static str, value: String) -> Result<Self, ClientError> {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| ClientError::InvalidHeader(name))?;
+        let header_value =
+            HeaderValue::from_str(&value).map_err(|_| ClientError::InvalidHeader(name))?;
+
+        self.headers.push((header_name, header_value));
+
+        Ok(self)
+    }
+}
+
+/// Response received by http layer
+#[derive(Debug)]
+pub struct ClientResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl ClientResponse {
+    /// Deserialize json body
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, ClientError> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Read header value, `None` if header is not sent
+    pub fn header<T: DeserializeOwned>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<T>, ClientError> {
+        match self.headers.get(name) {
+            Some(value) => {
+                let value = value
+                    .to_str()
+                    .map_err(|_| ClientError::InvalidHeader(name))?;
+
+                from_plain(value)
+                    .map(Some)
+                    .map_err(|_| ClientError::InvalidHeader(name))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Read comma separated list from header, `None` if header is not sent
+    pub fn header_list<T: DeserializeOwned>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<Vec<T>>, ClientError> {
+        match self.headers.get(name) {
+            Some(value) => {
+                let value = value
+                    .to_str()
+                    .map_err(|_| ClientError::InvalidHeader(name))?;
+
+                value
+                    .split(',')
+                    .map(|item| {
+                        from_plain(item.trim()).map_err(|_| ClientError::InvalidHeader(name))
+                    })
+                    .collect::<Result<Vec<T>, ClientError>>()
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Find cookie set by response
+    pub fn cookie(&self, name: &str) -> Option<Cookie<'static>> {
+        self.headers
+            .get_all(header::SET_COOKIE)
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|value| Cookie::parse(value.to_owned()).ok())
+            .find(|cookie| cookie.name() == name)
+    }
+}
+
+/// Convert value to string for path segment or header
+///
+/// Only strings, numbers and booleans can be passed this way
+pub fn to_plain<V: Serialize>(value: &V) -> Result<String, ClientError> {
+    use serde_json::Value;
+
+    match serde_json::to_value(value)? {
+        Value::String(string) => Ok(string),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::Bool(boolean) => Ok(boolean.to_string()),
+        _ => Err(ClientError::NotPlainValue),
+    }
+}
+
+/// Percent encoded path segment, see [`to_plain`]
+pub fn path_segment<V: Serialize>(value: &V) -> Result<String, ClientError> {
+    const SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .remove(b'~');
+
+    let plain = to_plain(value)?;
+
+    Ok(percent_encoding::utf8_percent_encode(&plain, SEGMENT).to_string())
+}
+
+fn from_plain<T: DeserializeOwned>(value: &str) -> Result<T, serde_json::Error> {
+    serde_json::from_value(serde_json::Value::String(value.to_owned()))
+        .or_else(|_| serde_json::from_str(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize)]
+    struct Query {
+        page: u32,
+        search: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Mode {
+        Fast,
+    }
+
+    #[test]
+    fn request_with_params() {
+        let id = path_segment(&"a b/c").unwrap();
+        let request = ClientRequest::new(Method::GET, format!("http://localhost/items/{}", id))
+            .query(&Query {
+                page: 2,
+                search: Some("x&y".to_owned()),
+            })
+            .unwrap()
+            .header("X-Request-Id", &42)
+            .unwrap()
+            .cookies(&[("session", "abc"), ("theme", "dark")])
+            .unwrap();
+
+        assert_eq!(
+            request.url,
+            "http://localhost/items/a%20b%2Fc?page=2&search=x%26y"
+        );
+        assert_eq!(
+            request.headers,
+            vec![
+                (
+                    HeaderName::from_static("x-request-id"),
+                    HeaderValue::from_static("42")
+                ),
+                (
+                    header::COOKIE,
+                    HeaderValue::from_static("session=abc; theme=dark")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn response_headers_and_cookies() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-rate-limit"),
+            HeaderValue::from_static("10"),
+        );
+        headers.insert(
+            HeaderName::from_static("x-mode"),
+            HeaderValue::from_static("fast"),
+        );
+        headers.insert(
+            HeaderName::from_static("x-tags"),
+            HeaderValue::from_static("a, b"),
+        );
+        headers.append(
+            header::SET_COOKIE,
+            HeaderValue::from_static("other=1; Path=/"),
+        );
+        headers.append(
+            header::SET_COOKIE,
+            HeaderValue::from_static("session=abc; HttpOnly"),
+        );
+
+        let response = ClientResponse {
+            status: StatusCode::OK,
+            headers,
+            body: Bytes::from_static(b"[1,2]"),
+        };
+
+        assert_eq!(response.header::<i64>("X-Rate-Limit").unwrap(), Some(10));
+        assert_eq!(response.header::<Mode>("X-Mode").unwrap(), Some(Mode::Fast));
+        assert_eq!(response.header::<String>("X-Missing").unwrap(), None);
+        assert!(response.header::<bool>("X-Mode").is_err());
+        assert_eq!(
+            response.header_list::<String>("X-Tags").unwrap(),
+            Some(vec!["a".to_owned(), "b".to_owned()])
+        );
+        assert_eq!(response.cookie("session").unwrap().value(), "abc");
+        assert_eq!(response.json::<Vec<u8>>().unwrap(), vec![1, 2]);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Session {
+        session: String,
+        theme: Option<String>,
+    }
+
+    #[test]
+    fn cookies_round_trip_to_extractor() {
+        let session = Session {
+            session: "a=b; c%d e".to_owned(),
+            theme: Some("dark".to_owned()),
+        };
+        let request = ClientRequest::new(Method::GET, "http://localhost".to_owned())
+            .cookies(&session)
+            .unwrap();
+
+        let (_, cookie) = request
+            .headers
+            .iter()
+            .find(|(name, _)| name == header::COOKIE)
+            .unwrap();
+        let req = actix_web::test::TestRequest::default()
+            .insert_header((header::COOKIE, cookie.clone()))
+            .to_http_request();
+
+        let parsed = crate::Cookies::<Session>::from_request_cookies(&req).unwrap();
+
+        assert_eq!(parsed.into_inner(), session);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Error;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use serde::de::DeserializeOwned;
+use std::future::{ready, Ready};
+use std::ops::Deref;
+
+/// Extract typed cookies from request
+///
+/// Cookies are deserialized the same way as query string,
+/// so `T` can contain any type that can be parsed from string.
+/// Generated code uses it for `in: cookie` parameters
+#[derive(Debug)]
+pub struct Cookies<T>(pub T);
+
+impl<T> Cookies<T> {
+    /// Unwrap into inner `T` value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Deserialize `T` from request cookies
+    pub fn from_request_cookies(req: &HttpRequest) -> Result<Self, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let pairs: Vec<(String, String)> = match req.cookies() {
+            Ok(cookies) => cookies
+                .iter()
+                .map(|cookie| (cookie.name().to_owned(), cookie.value().to_owned()))
+                .collect(),
+            Err(_) => vec![],
+        };
+
+        let encoded = serde_urlencoded::to_string(pairs)?;
+
+        serde_urlencoded::from_str(&encoded)
+            .map(Cookies)
+            .map_err(Error::CookiesError)
+    }
+}
+
+impl<T> Deref for Cookies<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for Cookies<T> {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Self::from_request_cookies(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{cookie::Cookie, http::StatusCode, test::TestRequest, ResponseError};
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct SessionCookies {
+        session_id: String,
+        visits: Option<i32>,
+    }
+
+    #[test]
+    fn deserialize_declared_cookies() {
+        let req = TestRequest::default()
+            .cookie(Cookie::new("session_id", "abc"))
+            .cookie(Cookie::new("visits", "3"))
+            .cookie(Cookie::new("other", "ignored"))
+            .to_http_request();
+
+        let cookies = Cookies::<SessionCookies>::from_request_cookies(&req).unwrap();
+
+        assert_eq!(cookies.session_id, "abc");
+        assert_eq!(cookies.visits, Some(3));
+    }
+
+    #[test]
+    fn missing_required_cookie_is_bad_request() {
+        let req = TestRequest::default()
+            .cookie(Cookie::new("visits", "3"))
+            .to_http_request();
+
+        let error = Cookies::<SessionCookies>::from_request_cookies(&req).unwrap_err();
+
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
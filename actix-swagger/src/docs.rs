@@ -0,0 +1,135 @@
+//! Documentation page for the served specification
+//!
+//! Scripts are not fetched from CDN, page is served with assets passed to [`Docs`],
+//! so documentation works without network access.
+//! Assets are not bundled with the crate, take them from `swagger-ui-dist` or `redoc` npm packages:
+//!
+//! ```ignore
+//! let docs = actix_swagger::Docs::swagger_ui(
+//!     include_str!("../assets/swagger-ui-bundle.js"),
+//!     include_str!("../assets/swagger-ui.css"),
+//! );
+//!
+//! DemoApi::new().openapi("/openapi.json").docs("/docs", docs)
+//! ```
+
+/// Documentation page with bundled assets
+#[derive(Debug, Clone)]
+pub struct Docs {
+    page: Page,
+    title: String,
+}
+
+#[derive(Debug, Clone)]
+enum Page {
+    /// Contents of `swagger-ui-bundle.js` and `swagger-ui.css` from `swagger-ui-dist`
+    SwaggerUi {
+        script: &'static str,
+        style: &'static str,
+    },
+    /// Contents of `redoc.standalone.js`
+    Redoc { script: &'static str },
+}
+
+impl Docs {
+    pub fn swagger_ui(script: &'static str, style: &'static str) -> Self {
+        Self {
+            page: Page::SwaggerUi { script, style },
+            title: "API".to_owned(),
+        }
+    }
+
+    pub fn redoc(script: &'static str) -> Self {
+        Self {
+            page: Page::Redoc { script },
+            title: "API".to_owned(),
+        }
+    }
+
+    /// Title of the page
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Files of the page relative to its path, `(name, content type, content)`,
+    /// empty name is the page itself
+    pub(crate) fn files(&self, path: &str, spec_url: &str) -> Vec<(String, &'static str, String)> {
+        let base = path.trim_end_matches('/');
+        let title = escape(&self.title);
+        let spec_url = escape(spec_url);
+
+        match &self.page {
+            Page::SwaggerUi { script, style } => vec![
+                (
+                    String::new(),
+                    "text/html; charset=utf-8",
+                    format!(
+                        r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<link rel="stylesheet" href="{base}/swagger-ui.css">
+</head>
+<body>
+<div id="swagger-ui"></div>
+<script src="{base}/swagger-ui-bundle.js"></script>
+<script>
+window.onload = function () {{
+  SwaggerUIBundle({{ url: "{spec_url}", dom_id: "#swagger-ui" }});
+}};
+</script>
+</body>
+</html>
+"##,
+                        title = title,
+                        base = base,
+                        spec_url = spec_url,
+                    ),
+                ),
+                (
+                    "swagger-ui-bundle.js".to_owned(),
+                    "application/javascript",
+                    script.to_string(),
+                ),
+                ("swagger-ui.css".to_owned(), "text/css", style.to_string()),
+            ],
+            Page::Redoc { script } => vec![
+                (
+                    String::new(),
+                    "text/html; charset=utf-8",
+                    format!(
+                        r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+</head>
+<body>
+<redoc spec-url="{spec_url}"></redoc>
+<script src="{base}/redoc.standalone.js"></script>
+</body>
+</html>
+"##,
+                        title = title,
+                        base = base,
+                        spec_url = spec_url,
+                    ),
+                ),
+                (
+                    "redoc.standalone.js".to_owned(),
+                    "application/javascript",
+                    script.to_string(),
+                ),
+            ],
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
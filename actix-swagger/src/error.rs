@@ -1,4 +1,5 @@
-use actix_web::ResponseError;
+use actix_http::Method;
+use actix_web::{http::StatusCode, ResponseError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,6 +10,83 @@ pub enum Error {
     SerdeUrlEncodedDeError(#[from] serde_urlencoded::de::Error),
     #[error("Serde url encoded serialization failure: {0}")]
     SerdeUrlEncodedSerError(#[from] serde_urlencoded::ser::Error),
+    #[error("Cookies deserialization failure: {0}")]
+    CookiesError(serde_urlencoded::de::Error),
+    #[error("Headers deserialization failure: {0}")]
+    HeadersError(serde_urlencoded::de::Error),
+    #[error("Security scheme {0} is not configured")]
+    SecuritySchemeNotConfigured(&'static str),
 }
 
-impl ResponseError for Error {}
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::CookiesError(_) | Error::HeadersError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Operations declared in specification but left without handler
+#[derive(Error, Debug)]
+#[error("Operations without handler: {}", list(.0))]
+pub struct UnboundOperations(pub Vec<(String, Method)>);
+
+/// Templates of different routes that match the same paths with the same method,
+/// `(method, first, second)`
+#[derive(Error, Debug)]
+#[error("Ambiguous routes: {}", ambiguous(.0))]
+pub struct AmbiguousRoutes(pub Vec<(Method, String, String)>);
+
+/// Security schemes required by routes but not authenticated in api
+#[derive(Error, Debug)]
+#[error("Security schemes without authenticator: {}", .0.join(", "))]
+pub struct UnauthenticatedSchemes(pub Vec<&'static str>);
+
+/// Failure of [`crate::Api::check`]
+#[derive(Error, Debug)]
+pub enum RegisterError {
+    #[error(transparent)]
+    Unbound(#[from] UnboundOperations),
+    #[error(transparent)]
+    Ambiguous(#[from] AmbiguousRoutes),
+    #[error(transparent)]
+    Unauthenticated(#[from] UnauthenticatedSchemes),
+}
+
+fn ambiguous(routes: &[(Method, String, String)]) -> String {
+    routes
+        .iter()
+        .map(|(method, first, second)| format!("{} {} and {}", method, first, second))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn list(operations: &[(String, Method)]) -> String {
+    operations
+        .iter()
+        .map(|(path, method)| format!("{} {}", method, path))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Failure of the request made by generated client
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Request failed: {0}")]
+    Transport(String),
+    #[error("Serde json failure: {0}")]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error("Serde url encoded serialization failure: {0}")]
+    SerdeUrlEncodedSerError(#[from] serde_urlencoded::ser::Error),
+    #[error("Value cannot be passed in path or header, only strings, numbers and booleans are supported")]
+    NotPlainValue,
+    #[error("Header {0} is invalid")]
+    InvalidHeader(&'static str),
+    #[error("Header {0} is missing")]
+    MissingHeader(&'static str),
+    #[error("Cookie {0} is missing")]
+    MissingCookie(&'static str),
+    #[error("Unexpected response status {0}")]
+    UnexpectedStatus(StatusCode),
+}
@@ -0,0 +1,155 @@
+//! Middleware and app data of single operations or groups of operations
+//!
+//! Generated api has `tag_*` helper for every tag and `route_*` helper for every operation:
+//!
+//! ```ignore
+//! DemoApi::new()
+//!     .bind_session_create(session_create)
+//!     .tag_oauth()
+//!     .wrap(RateLimit::new(10))
+//!     .route_session_create()
+//!     .app_data(web::Data::new(sessions))
+//! ```
+//!
+//! Configuration is applied when routes are registered, so it does not matter
+//! whether handlers are bound before or after it.
+
+use crate::Api;
+use actix_http::Method;
+use actix_service::boxed::BoxService;
+use actix_web::{
+    body::MessageBody,
+    dev::{Extensions, Service, ServiceRequest, ServiceResponse, Transform},
+    Error, Route,
+};
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+/// Service of the route, middleware passed to [`Group::wrap`] is applied to it
+pub type RouteService = BoxService<ServiceRequest, ServiceResponse, Error>;
+
+/// Change of the route made on register
+pub(crate) type Layer = Box<dyn FnOnce(Route) -> Route>;
+
+/// Operations of the api configured together
+pub struct Group<A> {
+    parent: A,
+    api: fn(&mut A) -> &mut Api,
+    operations: Vec<(String, Method)>,
+}
+
+impl<A> Group<A> {
+    /// Group of `operations` of the `parent`, `api` gives access to its [`Api`]
+    pub fn new(parent: A, api: fn(&mut A) -> &mut Api, operations: Vec<(&str, Method)>) -> Self {
+        Self {
+            parent,
+            api,
+            operations: operations
+                .into_iter()
+                .map(|(path, method)| (path.to_owned(), method))
+                .collect(),
+        }
+    }
+
+    /// Wrap every operation of the group with middleware, checked after security requirements
+    pub fn wrap<M, B>(self, middleware: M) -> A
+    where
+        M: Transform<
+                RouteService,
+                ServiceRequest,
+                Response = ServiceResponse<B>,
+                Error = Error,
+                InitError = (),
+            > + 'static,
+        B: MessageBody + 'static,
+    {
+        let middleware = Rc::new(middleware);
+
+        self.layer(move || {
+            let middleware = Shared(Rc::clone(&middleware));
+            Box::new(move |route: Route| route.wrap(middleware))
+        })
+    }
+
+    /// Add data available to handlers of the group, `web::Data<T>` for example
+    pub fn app_data<U: 'static>(self, data: U) -> A {
+        let mut extensions = Extensions::new();
+        extensions.insert(data);
+        let extensions = Rc::new(extensions);
+
+        self.layer(move || {
+            let data = AppData(Rc::clone(&extensions));
+            Box::new(move |route: Route| route.wrap(data))
+        })
+    }
+
+    fn layer<F: Fn() -> Layer>(mut self, layer: F) -> A {
+        let api = (self.api)(&mut self.parent);
+
+        for (path, method) in self.operations {
+            api.layer(path, method, layer());
+        }
+
+        self.parent
+    }
+}
+
+/// Middleware applied to several routes
+struct Shared<M>(Rc<M>);
+
+impl<S, M> Transform<S, ServiceRequest> for Shared<M>
+where
+    M: Transform<S, ServiceRequest>,
+{
+    type Response = M::Response;
+    type Error = M::Error;
+    type Transform = M::Transform;
+    type InitError = M::InitError;
+    type Future = M::Future;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        self.0.new_transform(service)
+    }
+}
+
+/// Middleware that adds data container to requests of the route
+struct AppData(Rc<Extensions>);
+
+impl<S> Transform<S, ServiceRequest> for AppData
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error>,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = AppDataMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(AppDataMiddleware {
+            service,
+            data: Rc::clone(&self.0),
+        }))
+    }
+}
+
+struct AppDataMiddleware<S> {
+    service: S,
+    data: Rc<Extensions>,
+}
+
+impl<S> Service<ServiceRequest> for AppDataMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error>,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = S::Future;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        req.add_data_container(Rc::clone(&self.data));
+        self.service.call(req)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::Error;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use serde::de::DeserializeOwned;
+use std::future::{ready, Ready};
+use std::ops::Deref;
+
+/// Extract typed headers from request
+///
+/// Headers are deserialized the same way as query string by lowercase names,
+/// the first value is taken when header is repeated.
+/// Generated code uses it for `in: header` parameters
+#[derive(Debug)]
+pub struct Headers<T>(pub T);
+
+impl<T> Headers<T> {
+    /// Unwrap into inner `T` value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Deserialize `T` from request headers
+    pub fn from_request_headers(req: &HttpRequest) -> Result<Self, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let headers = req.headers();
+        let pairs: Vec<(&str, &str)> = headers
+            .keys()
+            .filter_map(|name| {
+                let value = headers.get(name)?.to_str().ok()?;
+
+                Some((name.as_str(), value))
+            })
+            .collect();
+
+        let encoded = serde_urlencoded::to_string(pairs)?;
+
+        serde_urlencoded::from_str(&encoded)
+            .map(Headers)
+            .map_err(Error::HeadersError)
+    }
+}
+
+impl<T> Deref for Headers<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for Headers<T> {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Self::from_request_headers(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test::TestRequest, ResponseError};
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct RequestHeaders {
+        #[serde(rename = "x-request-id")]
+        x_request_id: i64,
+        #[serde(rename = "x-trace")]
+        x_trace: Option<String>,
+    }
+
+    #[test]
+    fn deserialize_declared_headers() {
+        let req = TestRequest::default()
+            .insert_header(("X-Request-Id", "42"))
+            .insert_header(("X-Other", "ignored"))
+            .to_http_request();
+
+        let headers = Headers::<RequestHeaders>::from_request_headers(&req).unwrap();
+
+        assert_eq!(headers.x_request_id, 42);
+        assert_eq!(headers.x_trace, None);
+    }
+
+    #[test]
+    fn missing_required_header_is_bad_request() {
+        let req = TestRequest::default()
+            .insert_header(("X-Trace", "abc"))
+            .to_http_request();
+
+        let error = Headers::<RequestHeaders>::from_request_headers(&req).unwrap_err();
+
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,23 +1,38 @@
 #![deny(warnings)]
 
+pub mod client;
+mod cookies;
+pub mod docs;
 mod error;
+pub mod group;
+mod headers;
+pub mod security;
 
-pub use error::Error;
+pub use cookies::Cookies;
+pub use docs::Docs;
+pub use error::{
+    AmbiguousRoutes, ClientError, Error, RegisterError, UnauthenticatedSchemes, UnboundOperations,
+};
+pub use group::Group;
+pub use headers::Headers;
 
 pub use actix_http::Method;
+pub use actix_web::cookie::Cookie;
 use actix_web::{
-    cookie::Cookie,
     dev::{AppService, HttpServiceFactory},
-    http::header::{self, HeaderName, HeaderValue, TryIntoHeaderValue},
-    FromRequest, HttpRequest, HttpResponse, Responder, Route, Scope,
+    http::header::{self, HeaderName, HeaderValue, TryIntoHeaderPair, TryIntoHeaderValue},
+    web, FromRequest, HttpRequest, HttpResponse, Responder, Route,
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use std::collections::HashMap;
 
 use actix_http::body::BoxBody;
 use actix_web::dev::Handler;
 pub use actix_web::http::StatusCode;
+use group::Layer;
+use security::{AuthFuture, Authenticator, Credentials, Requirement, Scheme, Security};
 use std::future::Future;
+use std::rc::Rc;
 
 /// Set content-type supported by actix-swagger
 #[derive(Debug)]
@@ -27,12 +42,12 @@ pub enum ContentType {
     // TextPlain,
 }
 
-impl ToString for ContentType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ContentType::Json => "application/json".to_string(),
-            // ContentType::TextPlain => "text/plain".to_string(),
-            ContentType::FormData => "application/x-www-form-urlencoded".to_string(),
+            ContentType::Json => write!(f, "application/json"),
+            // ContentType::TextPlain => write!(f, "text/plain"),
+            ContentType::FormData => write!(f, "application/x-www-form-urlencoded"),
         }
     }
 }
@@ -42,7 +57,7 @@ pub struct Answer<'a, T> {
     response: T,
     status_code: Option<StatusCode>,
     cookies: Vec<Cookie<'a>>,
-    headers: HashMap<String, HeaderValue>,
+    headers: Vec<(HeaderName, HeaderValue)>,
     content_type: Option<ContentType>,
 }
 
@@ -52,23 +67,80 @@ impl<'a, T: Serialize> Answer<'a, T> {
             response,
             status_code: None,
             cookies: vec![],
-            headers: HashMap::new(),
+            headers: vec![],
             content_type: None,
         }
     }
 
-    /// Set header to answer
-    pub fn header<V>(mut self, key: String, value: V) -> Self
+    /// Set header to answer, replacing any previous values of the same header
+    pub fn header<V>(self, key: String, value: V) -> Self
     where
         V: TryIntoHeaderValue,
     {
-        if let Ok(value) = value.try_into_value() {
-            self.headers.insert(key, value);
+        match (key.parse::<HeaderName>(), value.try_into_value()) {
+            (Ok(name), Ok(value)) => self.insert_header((name, value)),
+            _ => self,
+        }
+    }
+
+    /// Insert header to answer, replacing all previous values of the same header
+    ///
+    /// Replaced header keeps position of its first value
+    pub fn insert_header(mut self, header: impl TryIntoHeaderPair) -> Self {
+        if let Ok((name, value)) = header.try_into_pair() {
+            match self.headers.iter().position(|(key, _)| *key == name) {
+                Some(index) => {
+                    self.headers[index].1 = value;
+
+                    let rest = self.headers.split_off(index + 1);
+                    self.headers
+                        .extend(rest.into_iter().filter(|(key, _)| *key != name));
+                }
+                None => self.headers.push((name, value)),
+            }
         }
 
         self
     }
 
+    /// Append header to answer, keeping previous values of the same header
+    ///
+    /// Values are sent in the order they were appended
+    pub fn append_header(mut self, header: impl TryIntoHeaderPair) -> Self {
+        if let Ok(pair) = header.try_into_pair() {
+            self.headers.push(pair);
+        }
+
+        self
+    }
+
+    /// Insert every header from list, see [`Answer::insert_header`]
+    pub fn insert_headers<H, I>(self, headers: I) -> Self
+    where
+        H: TryIntoHeaderPair,
+        I: IntoIterator<Item = H>,
+    {
+        headers
+            .into_iter()
+            .fold(self, |answer, header| answer.insert_header(header))
+    }
+
+    /// Append every header from list, see [`Answer::append_header`]
+    pub fn append_headers<H, I>(self, headers: I) -> Self
+    where
+        H: TryIntoHeaderPair,
+        I: IntoIterator<Item = H>,
+    {
+        headers
+            .into_iter()
+            .fold(self, |answer, header| answer.append_header(header))
+    }
+
+    /// Headers in the order they will be written to response
+    pub fn headers(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
+        self.headers.iter().map(|(name, value)| (name, value))
+    }
+
     /// Add cookie to answer
     pub fn cookie(mut self, cookie: Cookie<'a>) -> Self {
         self.cookies.push(cookie);
@@ -117,10 +189,8 @@ impl<'a, T: Serialize> Responder for Answer<'a, T> {
             response = response.append_header((header::CONTENT_TYPE, content_type.to_string()));
         }
 
-        for (name, value) in self.headers {
-            if let Ok(header_name) = name.parse::<HeaderName>() {
-                response = response.append_header((header_name, value))
-            }
+        for header in self.headers {
+            response = response.append_header(header);
         }
 
         for cookie in self.cookies {
@@ -131,12 +201,46 @@ impl<'a, T: Serialize> Responder for Answer<'a, T> {
     }
 }
 
+/// Serialize only body of response variant that also carries headers
+///
+/// Used by generated `Response` enums with `#[serde(serialize_with)]`
+pub fn serialize_body<B, H, S>(body: &B, _headers: &H, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B: Serialize,
+    S: Serializer,
+{
+    body.serialize(serializer)
+}
+
+/// Serialize response variant that carries only headers as empty body
+pub fn serialize_empty<H, S>(_headers: &H, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_unit()
+}
+
 // https://actix.rs/docs/errors/
 
-/// Handler scope and routes
+/// Handler routes, registered in application under common prefix
 pub struct Api {
-    root: Scope,
-    resources: HashMap<String, Route>,
+    /// Path of the scope every route is mounted under, `/api/v2` for example
+    prefix: String,
+    /// Paths in order of binding, each method gets its own route
+    resources: Vec<(String, Vec<(Method, Route)>)>,
+    requirements: HashMap<String, Vec<(Method, Requirement)>>,
+    authenticators: HashMap<&'static str, Authenticator>,
+    /// Middleware and app data of operations, in order of configuration
+    layers: Vec<(String, Method, Layer)>,
+
+    /// Operations of the specification, each one should get a handler
+    operations: Vec<(String, Method)>,
+    bound: Vec<(String, Method)>,
+    strict: bool,
+
+    /// Path and content of the served specification
+    openapi: Option<(String, &'static str)>,
+    docs: Vec<(String, Docs)>,
 }
 
 impl Default for Api {
@@ -148,9 +252,185 @@ impl Default for Api {
 impl Api {
     pub fn new() -> Self {
         Api {
-            root: Scope::new(""),
-            resources: HashMap::new(),
+            prefix: String::new(),
+            resources: vec![],
+            requirements: HashMap::new(),
+            authenticators: HashMap::new(),
+            layers: vec![],
+            operations: vec![],
+            bound: vec![],
+            strict: false,
+            openapi: None,
+            docs: vec![],
+        }
+    }
+
+    /// Mount every route under `prefix`, generated api passes path of the server from specification
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        let prefix = prefix.trim_end_matches('/');
+
+        self.prefix = match prefix.is_empty() || prefix.starts_with('/') {
+            true => prefix.to_owned(),
+            false => format!("/{}", prefix),
+        };
+        self
+    }
+
+    /// Declare operation of the specification, handler is attached later with [`Api::bind`]
+    pub fn operation(mut self, path: &str, method: Method) -> Self {
+        self.operations.push((path.to_owned(), method));
+        self
+    }
+
+    /// Configure middleware and app data of `operations`, path and method of each
+    pub fn group(self, operations: Vec<(&str, Method)>) -> Group<Self> {
+        Group::new(self, |api| api, operations)
+    }
+
+    pub(crate) fn layer(&mut self, path: String, method: Method, layer: Layer) {
+        self.layers.push((path, method, layer));
+    }
+
+    /// Panic in `register` when some declared operation has no handler
+    ///
+    /// Unbound operations answer `501 Not Implemented` in lenient mode, which is the default
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Declared operations without handler, in order of declaration
+    pub fn unbound(&self) -> Vec<(String, Method)> {
+        self.operations
+            .iter()
+            .filter(|operation| !self.bound.contains(operation))
+            .cloned()
+            .collect()
+    }
+
+    /// Serve specification document in JSON at `path`, `/openapi.json` for example
+    pub fn openapi(mut self, path: &str, document: &'static str) -> Self {
+        self.openapi = Some((path.to_owned(), document));
+        self
+    }
+
+    /// Serve documentation page at `path`, its assets are served under the same path
+    ///
+    /// Page loads specification served with [`Api::openapi`]
+    pub fn docs(mut self, path: &str, docs: Docs) -> Self {
+        self.docs.push((path.to_owned(), docs));
+        self
+    }
+
+    /// Bound routes whose templates match the same paths with the same method,
+    /// `/a/{x}` and `/a/{y}` for example
+    pub fn ambiguous(&self) -> Vec<(Method, String, String)> {
+        let mut found = vec![];
+
+        for (index, (first, _)) in self.resources.iter().enumerate() {
+            for (second, _) in self.resources[index + 1..].iter() {
+                if template(first) != template(second) {
+                    continue;
+                }
+
+                let mut methods: Vec<&Method> = vec![];
+
+                for (path, method) in self.bound.iter() {
+                    if path == first
+                        && !methods.contains(&method)
+                        && self.bound.contains(&(second.clone(), method.clone()))
+                    {
+                        methods.push(method);
+                    }
+                }
+
+                for method in methods {
+                    found.push((method.clone(), first.clone(), second.clone()));
+                }
+            }
         }
+
+        found
+    }
+
+    /// Security schemes required by bound routes but not authenticated with [`Api::security_scheme`],
+    /// in order of requirements
+    ///
+    /// Requests to such routes could never be authorized
+    pub fn unauthenticated(&self) -> Vec<&'static str> {
+        let mut found = vec![];
+
+        for (path, method) in self.bound.iter() {
+            let requirements = self.requirements.get(path).into_iter().flatten();
+
+            for (_, requirement) in requirements.filter(|(required, _)| required == method) {
+                for (name, _) in requirement
+                    .iter()
+                    .flat_map(|alternative| alternative.iter())
+                {
+                    if !self.authenticators.contains_key(name) && !found.contains(name) {
+                        found.push(*name);
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Fails when some declared operation has no handler, routes are ambiguous
+    /// or required security scheme is not authenticated,
+    /// call it before passing api to `App::service` that panics on them
+    pub fn check(&self) -> Result<(), RegisterError> {
+        let unbound = self.unbound();
+
+        if !unbound.is_empty() {
+            return Err(UnboundOperations(unbound).into());
+        }
+
+        let unauthenticated = self.unauthenticated();
+
+        if !unauthenticated.is_empty() {
+            return Err(UnauthenticatedSchemes(unauthenticated).into());
+        }
+
+        let ambiguous = self.ambiguous();
+
+        if !ambiguous.is_empty() {
+            return Err(AmbiguousRoutes(ambiguous).into());
+        }
+
+        Ok(())
+    }
+
+    /// Authenticate security scheme with `authenticate` function
+    ///
+    /// Authenticated identity can be extracted in handlers with [`security::Authorized`]
+    pub fn security_scheme<C, A, F>(
+        mut self,
+        name: &'static str,
+        scheme: Scheme,
+        authenticate: F,
+    ) -> Self
+    where
+        C: Credentials,
+        A: Clone + 'static,
+        F: Fn(&HttpRequest, C, &'static [&'static str]) -> AuthFuture<A> + 'static,
+    {
+        self.authenticators
+            .insert(name, security::authenticator(scheme, authenticate));
+
+        self
+    }
+
+    /// Require security schemes for route, checked before handler is called
+    pub fn security(mut self, path: &str, method: Method, requirement: Requirement) -> Self {
+        self.requirements
+            .entry(path.to_owned())
+            .or_default()
+            .push((method, requirement));
+
+        self
     }
 
     /// Attach route to path
@@ -162,27 +442,602 @@ impl Api {
         F: Handler<T, Future = R>,
         F::Output: Responder + 'static,
     {
-        take_mut::take(
-            self.resources
-                .entry(path.to_owned())
-                .or_insert_with(Route::new),
-            |route| route.method(method).to(handler),
-        );
+        self.bound.push((path.to_owned(), method.clone()));
+
+        let route = web::method(method.clone()).to(handler);
+
+        match self.resources.iter_mut().find(|(key, _)| key == path) {
+            Some((_, routes)) => routes.push((method, route)),
+            None => self
+                .resources
+                .push((path.to_owned(), vec![(method, route)])),
+        }
 
         self
     }
+
+    fn register_routes(mut self, config: &mut AppService) {
+        let authenticators = Rc::new(self.authenticators);
+        let mut layers = self.layers;
+        let prefix = self.prefix;
+
+        // Served files go first, so `/{id}` of the specification does not shadow `/openapi.json`
+        if let Some((path, document)) = &self.openapi {
+            web::resource(format!("{}{}", prefix, path))
+                .route(static_file("application/json", document.to_string()))
+                .register(config);
+        }
+
+        for (path, docs) in self.docs.iter() {
+            let spec_url = match &self.openapi {
+                Some((spec_url, _)) => spec_url,
+                None => panic!("Documentation at {} requires Api::openapi", path),
+            };
+
+            // Page refers to its assets and specification by absolute url
+            let page_url = format!("{}{}", prefix, path);
+            let spec_url = format!("{}{}", prefix, spec_url);
+
+            for (name, content_type, content) in docs.files(&page_url, &spec_url) {
+                let file_url = match name.is_empty() {
+                    true => page_url.clone(),
+                    false => format!("{}/{}", page_url.trim_end_matches('/'), name),
+                };
+
+                web::resource(file_url)
+                    .route(static_file(content_type, content))
+                    .register(config);
+            }
+        }
+
+        // Actix matches routes in order of registration, so `/users/me` goes before `/users/{id}`
+        self.resources
+            .sort_by_cached_key(|(path, _)| dynamic_segments(path));
+
+        for (key, mut routes) in self.resources {
+            let explicit_options = routes.iter().any(|(method, _)| method == Method::OPTIONS);
+
+            let mut allow: Vec<&str> = routes.iter().map(|(method, _)| method.as_str()).collect();
+            if !explicit_options {
+                allow.push(Method::OPTIONS.as_str());
+            }
+            let allow = allow.join(", ");
+
+            if !explicit_options {
+                let route =
+                    with_allow(StatusCode::NO_CONTENT, allow.clone()).method(Method::OPTIONS);
+                routes.push((Method::OPTIONS, route));
+            }
+
+            // Resources are registered without scope, so apis sharing a prefix can be mounted together
+            let mut resource = web::resource(format!("{}{}", prefix, key))
+                .default_service(with_allow(StatusCode::METHOD_NOT_ALLOWED, allow));
+
+            for (method, route) in routes {
+                let (own, rest): (Vec<_>, Vec<_>) = layers
+                    .into_iter()
+                    .partition(|(path, layer_method, _)| path == &key && layer_method == method);
+                layers = rest;
+
+                let route = own
+                    .into_iter()
+                    .fold(route, |route, (_, _, layer)| layer(route));
+
+                resource = resource.route(route);
+            }
+
+            match self.requirements.remove(&key) {
+                Some(requirements) => resource
+                    .wrap(Security {
+                        requirements: Rc::new(requirements),
+                        authenticators: Rc::clone(&authenticators),
+                    })
+                    .register(config),
+                None => resource.register(config),
+            }
+        }
+    }
+}
+
+/// Which segments of the path template have parameters, static segments sort first
+fn dynamic_segments(path: &str) -> Vec<bool> {
+    path.split('/')
+        .map(|segment| segment.contains('{'))
+        .collect()
+}
+
+/// Path template without names of parameters, `/a/{x:\d+}` becomes `/a/{:\d+}`
+fn template(path: &str) -> String {
+    let mut result = String::with_capacity(path.len());
+    let mut depth = 0;
+    let mut name = false;
+
+    for character in path.chars() {
+        match character {
+            '{' if depth == 0 => {
+                depth = 1;
+                name = true;
+                result.push(character);
+            }
+            ':' if name => {
+                name = false;
+                result.push(character);
+            }
+            '}' if depth == 1 => {
+                depth = 0;
+                name = false;
+                result.push(character);
+            }
+            '{' => {
+                depth += 1;
+                result.push(character);
+            }
+            '}' if depth > 1 => {
+                depth -= 1;
+                result.push(character);
+            }
+            _ if name => {}
+            _ => result.push(character),
+        }
+    }
+
+    result
+}
+
+/// Route answering with empty body and `Allow` header listing methods of the path
+fn with_allow(status: StatusCode, allow: String) -> Route {
+    web::route().to(move || {
+        let allow = allow.clone();
+
+        async move {
+            HttpResponse::build(status)
+                .insert_header((header::ALLOW, allow))
+                .finish()
+        }
+    })
+}
+
+/// Route answering with the same content on every request
+fn static_file(content_type: &'static str, content: String) -> Route {
+    let content = web::Bytes::from(content);
+
+    web::get().to(move || {
+        let content = content.clone();
+
+        async move { HttpResponse::Ok().content_type(content_type).body(content) }
+    })
 }
 
 impl HttpServiceFactory for Api {
     fn register(mut self, config: &mut AppService) {
-        let keys: Vec<String> = self.resources.keys().cloned().collect();
+        let unbound = self.unbound();
 
-        for key in keys.iter() {
-            if let Some(resource) = self.resources.remove(key) {
-                self.root = self.root.route(key, resource);
-            }
+        if self.strict && !unbound.is_empty() {
+            panic!("{}", UnboundOperations(unbound));
+        }
+
+        let unauthenticated = self.unauthenticated();
+
+        if !unauthenticated.is_empty() {
+            panic!("{}", UnauthenticatedSchemes(unauthenticated));
         }
 
-        self.root.register(config);
+        for (path, method) in unbound {
+            self = self.bind(&path, method, not_implemented);
+        }
+
+        let ambiguous = self.ambiguous();
+
+        if !ambiguous.is_empty() {
+            panic!("{}", AmbiguousRoutes(ambiguous));
+        }
+
+        self.register_routes(config);
+    }
+}
+
+async fn not_implemented() -> HttpResponse {
+    HttpResponse::NotImplemented().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn answer_appends_headers_in_order() {
+        let answer = Answer::new(())
+            .append_header(("Link", "</users?page=2>; rel=\"next\""))
+            .append_header(("Link", "</users?page=5>; rel=\"last\""));
+
+        let response = answer.respond_to(&TestRequest::default().to_http_request());
+        let links: Vec<_> = response.headers().get_all(header::LINK).collect();
+
+        assert_eq!(
+            links,
+            vec![
+                "</users?page=2>; rel=\"next\"",
+                "</users?page=5>; rel=\"last\""
+            ]
+        );
+    }
+
+    #[test]
+    fn answer_insert_replaces_all_values() {
+        let answer = Answer::new(())
+            .append_header(("X-First", "1"))
+            .append_header(("Link", "<a>"))
+            .append_header(("X-Second", "2"))
+            .append_header(("Link", "<b>"))
+            .insert_header(("Link", "<c>"));
+
+        let headers: Vec<_> = answer
+            .headers()
+            .map(|(name, value)| (name.as_str(), value.to_str().unwrap()))
+            .collect();
+
+        assert_eq!(
+            headers,
+            vec![("x-first", "1"), ("link", "<c>"), ("x-second", "2")]
+        );
+    }
+
+    #[test]
+    fn answer_header_keeps_insert_semantics() {
+        let answer = Answer::new(())
+            .header("X-Request-Id".to_owned(), "first")
+            .header("X-Request-Id".to_owned(), "second");
+
+        let values: Vec<_> = answer.headers().map(|(_, value)| value.clone()).collect();
+
+        assert_eq!(values, vec![HeaderValue::from_static("second")]);
+    }
+
+    fn declared() -> Api {
+        Api::new()
+            .operation("/users", Method::GET)
+            .operation("/session", Method::POST)
+            .bind("/users", Method::GET, || async {
+                HttpResponse::Ok().finish()
+            })
+    }
+
+    #[test]
+    fn unbound_operations_are_listed() {
+        let unbound = declared().unbound();
+
+        assert_eq!(unbound, vec![("/session".to_owned(), Method::POST)]);
+        assert_eq!(
+            UnboundOperations(unbound).to_string(),
+            "Operations without handler: POST /session"
+        );
+    }
+
+    #[test]
+    fn check_fails_before_register() {
+        assert!(matches!(declared().check(), Err(RegisterError::Unbound(_))));
+
+        let api = declared().bind("/session", Method::POST, || async {
+            HttpResponse::Ok().finish()
+        });
+        assert!(api.check().is_ok());
+
+        let api = api
+            .bind("/a/{x}", Method::GET, || async {
+                HttpResponse::Ok().finish()
+            })
+            .bind("/a/{y}", Method::GET, || async {
+                HttpResponse::Ok().finish()
+            });
+        assert!(matches!(api.check(), Err(RegisterError::Ambiguous(_))));
+    }
+
+    #[actix_rt::test]
+    async fn unbound_operation_is_not_implemented() {
+        let app = actix_web::test::init_service(actix_web::App::new().service(declared())).await;
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/session")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::NOT_IMPLEMENTED);
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/users")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[actix_rt::test]
+    #[should_panic(expected = "Operations without handler: POST /session")]
+    async fn strict_api_panics_on_unbound_operation() {
+        actix_web::test::init_service(actix_web::App::new().service(declared().strict(true))).await;
+    }
+
+    #[actix_rt::test]
+    async fn static_segments_are_matched_first() {
+        let api = Api::new()
+            .bind("/users/{id}", Method::GET, || async {
+                HttpResponse::Ok().body("user")
+            })
+            .bind("/users/me", Method::GET, || async {
+                HttpResponse::Ok().body("me")
+            });
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/users/me")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "me");
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/users/1")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "user");
+    }
+
+    #[actix_rt::test]
+    async fn methods_of_path_get_own_routes() {
+        let api = Api::new()
+            .bind("/session", Method::GET, || async {
+                HttpResponse::Ok().body("get")
+            })
+            .bind("/session", Method::POST, || async {
+                HttpResponse::Ok().body("post")
+            });
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/session")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "get");
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/session")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "post");
+
+        let req = actix_web::test::TestRequest::delete()
+            .uri("/session")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(
+            res.headers().get(header::ALLOW).unwrap(),
+            "GET, POST, OPTIONS"
+        );
+
+        let req = actix_web::test::TestRequest::default()
+            .method(Method::OPTIONS)
+            .uri("/session")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            res.headers().get(header::ALLOW).unwrap(),
+            "GET, POST, OPTIONS"
+        );
+    }
+
+    #[actix_rt::test]
+    async fn routes_are_mounted_under_prefix() {
+        let api = Api::new()
+            .with_prefix("api/v2/")
+            .bind("/users", Method::GET, || async {
+                HttpResponse::Ok().body("users")
+            })
+            .openapi("/openapi.json", "{}")
+            .docs("/docs", Docs::redoc(""));
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/api/v2/users")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "users");
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/users")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/api/v2/docs")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        let page = String::from_utf8(body.to_vec()).unwrap();
+        assert!(page.contains(r#"<redoc spec-url="/api/v2/openapi.json"></redoc>"#));
+        assert!(page.contains(r#"<script src="/api/v2/docs/redoc.standalone.js"></script>"#));
+    }
+
+    struct Wrapper {
+        api: Api,
+    }
+
+    impl Wrapper {
+        fn tag_session(self) -> Group<Self> {
+            Group::new(
+                self,
+                |wrapper| &mut wrapper.api,
+                vec![("/session", Method::GET), ("/session", Method::POST)],
+            )
+        }
+    }
+
+    #[actix_rt::test]
+    async fn group_wraps_and_adds_data() {
+        let api =
+            Api::new()
+                .group(vec![("/session", Method::GET)])
+                .app_data(web::Data::new("get"))
+                .bind(
+                    "/session",
+                    Method::GET,
+                    |name: web::Data<&'static str>| async move {
+                        HttpResponse::Ok().body(*name.get_ref())
+                    },
+                )
+                .bind("/session", Method::POST, || async {
+                    HttpResponse::Ok().finish()
+                })
+                .bind("/users", Method::GET, || async {
+                    HttpResponse::Ok().finish()
+                });
+
+        let api = Wrapper { api }
+            .tag_session()
+            .wrap(actix_web::middleware::DefaultHeaders::new().add(("X-Tag", "session")))
+            .api;
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/session")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.headers().get("X-Tag").unwrap(), "session");
+        assert_eq!(actix_web::test::read_body(res).await, "get");
+
+        let req = actix_web::test::TestRequest::post()
+            .uri("/session")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(res.headers().get("X-Tag").unwrap(), "session");
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/users")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert!(res.headers().get("X-Tag").is_none());
+    }
+
+    #[actix_rt::test]
+    async fn apis_share_prefix() {
+        let users = Api::new()
+            .with_prefix("/api")
+            .bind("/users", Method::GET, || async {
+                HttpResponse::Ok().body("users")
+            });
+        let session = Api::new()
+            .with_prefix("/api")
+            .bind("/session", Method::GET, || async {
+                HttpResponse::Ok().body("session")
+            });
+        let app =
+            actix_web::test::init_service(actix_web::App::new().service(users).service(session))
+                .await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/api/session")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "session");
+    }
+
+    #[test]
+    fn template_drops_parameter_names() {
+        assert_eq!(template("/a/{x}/b"), "/a/{}/b");
+        assert_eq!(template(r"/a/{x:\d{2}}.{ext}"), r"/a/{:\d{2}}.{}");
+    }
+
+    #[test]
+    fn ambiguous_routes_are_reported() {
+        let handler = || async { HttpResponse::Ok().finish() };
+        let api = Api::new()
+            .bind("/a/{x}", Method::GET, handler)
+            .bind("/a/{x}", Method::POST, handler)
+            .bind("/a/{y}", Method::GET, handler)
+            .bind("/a/{y:\\d+}", Method::GET, handler)
+            .bind("/b/{z}", Method::GET, handler);
+
+        let ambiguous = api.ambiguous();
+
+        assert_eq!(
+            ambiguous,
+            vec![(Method::GET, "/a/{x}".to_owned(), "/a/{y}".to_owned())]
+        );
+        assert_eq!(
+            AmbiguousRoutes(ambiguous).to_string(),
+            "Ambiguous routes: GET /a/{x} and /a/{y}"
+        );
+    }
+
+    #[actix_rt::test]
+    #[should_panic(expected = "Ambiguous routes: GET /a/{x} and /a/{y}")]
+    async fn ambiguous_routes_panic_on_register() {
+        let handler = || async { HttpResponse::Ok().finish() };
+        let api =
+            Api::new()
+                .bind("/a/{x}", Method::GET, handler)
+                .bind("/a/{y}", Method::GET, handler);
+
+        actix_web::test::init_service(actix_web::App::new().service(api)).await;
+    }
+
+    #[actix_rt::test]
+    async fn serves_openapi_and_docs() {
+        let api = Api::new()
+            .openapi("/openapi.json", r#"{"openapi":"3.0.1"}"#)
+            .docs("/docs", Docs::redoc("/* redoc */").title("Demo"));
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/openapi.json")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, r#"{"openapi":"3.0.1"}"#);
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/docs")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        let page = String::from_utf8(body.to_vec()).unwrap();
+        assert!(page.contains(r#"<redoc spec-url="/openapi.json"></redoc>"#));
+        assert!(page.contains(r#"<script src="/docs/redoc.standalone.js"></script>"#));
+        assert!(page.contains("<title>Demo</title>"));
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/docs/redoc.standalone.js")
+            .to_request();
+        let res = actix_web::test::call_service(&app, req).await;
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/javascript"
+        );
+    }
+
+    #[actix_rt::test]
+    async fn openapi_is_not_shadowed_by_dynamic_route() {
+        async fn item() -> &'static str {
+            "item"
+        }
+
+        let api = Api::new()
+            .bind("/{id}", Method::GET, item)
+            .openapi("/openapi.json", r#"{"openapi":"3.0.1"}"#)
+            .docs("/docs", Docs::redoc("/* redoc */"));
+        let app = actix_web::test::init_service(actix_web::App::new().service(api)).await;
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/openapi.json")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, r#"{"openapi":"3.0.1"}"#);
+
+        let req = actix_web::test::TestRequest::get()
+            .uri("/docs")
+            .to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert!(String::from_utf8(body.to_vec()).unwrap().contains("<redoc"));
+
+        let req = actix_web::test::TestRequest::get().uri("/42").to_request();
+        let body = actix_web::test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "item");
     }
 }
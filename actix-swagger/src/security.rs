@@ -0,0 +1,470 @@
+//! Security schemes support for generated code
+//!
+//! Generated code declares trait per security scheme and requirements per operation.
+//! Application implements scheme trait for its identity type and registers it in api,
+//! then [`Api`](crate::Api) checks requirements before calling handler.
+
+use actix_web::{
+    body::EitherBody,
+    dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    error::ErrorUnauthorized,
+    http::header,
+    FromRequest, HttpMessage, HttpRequest,
+};
+use std::collections::HashMap;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Future returned by security scheme authentication
+pub type AuthFuture<T> = Pin<Box<dyn Future<Output = Result<T, actix_web::Error>>>>;
+
+/// Security requirements of an operation
+///
+/// Request is authorized when any of the alternatives is satisfied (OR),
+/// alternative is satisfied when every scheme in it is authenticated with required scopes (AND).
+/// Empty alternative allows anonymous access
+pub type Requirement = &'static [&'static [(&'static str, &'static [&'static str])]];
+
+pub(crate) type Authenticator =
+    Rc<dyn Fn(&HttpRequest, &'static [&'static str]) -> AuthFuture<Identity>>;
+
+/// Authenticated identity, inserted into request extensions once its whole alternative is satisfied
+pub(crate) type Identity = Box<dyn FnOnce(&HttpRequest)>;
+
+/// Location of the api key
+#[derive(Debug, Clone, Copy)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
+
+/// How credentials of the security scheme are passed with request
+#[derive(Debug, Clone, Copy)]
+pub enum Scheme {
+    /// `Authorization: Bearer <token>`, used for http bearer, oauth2 and openIdConnect
+    Bearer,
+    /// `Authorization: Basic <base64>`
+    Basic,
+    /// Api key in header, query or cookie with name
+    ApiKey {
+        location: ApiKeyLocation,
+        name: &'static str,
+    },
+}
+
+/// Credentials that can be read from request for a scheme
+pub trait Credentials: Sized {
+    fn extract(req: &HttpRequest, scheme: &Scheme) -> Option<Self>;
+}
+
+fn authorization(req: &HttpRequest, prefix: &str) -> Option<String> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+
+    match value.get(..prefix.len()) {
+        Some(found) if found.eq_ignore_ascii_case(prefix) => {
+            Some(value[prefix.len()..].trim().to_owned())
+        }
+        _ => None,
+    }
+}
+
+/// Token from `Authorization: Bearer` header
+#[derive(Debug, Clone)]
+pub struct Bearer(pub String);
+
+impl Credentials for Bearer {
+    fn extract(req: &HttpRequest, _: &Scheme) -> Option<Self> {
+        authorization(req, "Bearer ").map(Bearer)
+    }
+}
+
+/// Decoded `Authorization: Basic` header
+#[derive(Debug, Clone)]
+pub struct Basic {
+    pub user_id: String,
+    pub password: Option<String>,
+}
+
+impl Credentials for Basic {
+    fn extract(req: &HttpRequest, _: &Scheme) -> Option<Self> {
+        let encoded = authorization(req, "Basic ")?;
+        let decoded = base64::decode(encoded).ok()?;
+        let decoded = String::from_utf8(decoded).ok()?;
+
+        let mut parts = decoded.splitn(2, ':');
+        let user_id = parts.next()?.to_owned();
+        let password = parts.next().map(ToOwned::to_owned);
+
+        Some(Basic { user_id, password })
+    }
+}
+
+/// Api key from header, query or cookie
+#[derive(Debug, Clone)]
+pub struct ApiKey(pub String);
+
+impl Credentials for ApiKey {
+    fn extract(req: &HttpRequest, scheme: &Scheme) -> Option<Self> {
+        let (location, name) = match scheme {
+            Scheme::ApiKey { location, name } => (location, *name),
+            _ => return None,
+        };
+
+        match location {
+            ApiKeyLocation::Header => req
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| ApiKey(value.to_owned())),
+            ApiKeyLocation::Query => {
+                serde_urlencoded::from_str::<Vec<(String, String)>>(req.query_string())
+                    .ok()?
+                    .into_iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| ApiKey(value))
+            }
+            ApiKeyLocation::Cookie => req.cookie(name).map(|c| ApiKey(c.value().to_owned())),
+        }
+    }
+}
+
+/// Create authenticator for scheme that stores authenticated identity in request extensions
+pub(crate) fn authenticator<C, A, F>(scheme: Scheme, authenticate: F) -> Authenticator
+where
+    C: Credentials,
+    A: Clone + 'static,
+    F: Fn(&HttpRequest, C, &'static [&'static str]) -> AuthFuture<A> + 'static,
+{
+    Rc::new(
+        move |req: &HttpRequest, scopes| match C::extract(req, &scheme) {
+            Some(credentials) => {
+                let identity = authenticate(req, credentials, scopes);
+
+                Box::pin(async move {
+                    let identity = identity.await?;
+                    let insert: Identity = Box::new(move |req: &HttpRequest| {
+                        req.extensions_mut().insert(identity);
+                    });
+                    Ok(insert)
+                })
+            }
+            None => Box::pin(ready(Err(ErrorUnauthorized("Credentials are missing")))),
+        },
+    )
+}
+
+/// Check that request satisfies any of the requirement alternatives
+///
+/// Only identities of the satisfied alternative are available to the handler
+pub(crate) async fn authorize(
+    req: &HttpRequest,
+    requirement: Requirement,
+    authenticators: &HashMap<&'static str, Authenticator>,
+) -> Result<(), actix_web::Error> {
+    let mut last_error = None;
+
+    'alternatives: for alternative in requirement.iter() {
+        let mut identities = vec![];
+
+        for (name, scopes) in alternative.iter() {
+            let result = match authenticators.get(name) {
+                Some(authenticate) => authenticate(req, scopes).await,
+                None => Err(crate::Error::SecuritySchemeNotConfigured(name).into()),
+            };
+
+            match result {
+                Ok(identity) => identities.push(identity),
+                Err(error) => {
+                    last_error = Some(error);
+                    continue 'alternatives;
+                }
+            }
+        }
+
+        for insert in identities {
+            insert(req);
+        }
+
+        return Ok(());
+    }
+
+    Err(last_error.unwrap_or_else(|| ErrorUnauthorized("Unauthorized")))
+}
+
+/// Middleware that checks security requirements of the resource methods
+pub(crate) struct Security {
+    pub requirements: Rc<Vec<(actix_web::http::Method, Requirement)>>,
+    pub authenticators: Rc<HashMap<&'static str, Authenticator>>,
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Security
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = SecurityMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SecurityMiddleware {
+            service: Rc::new(service),
+            requirements: Rc::clone(&self.requirements),
+            authenticators: Rc::clone(&self.authenticators),
+        }))
+    }
+}
+
+pub(crate) struct SecurityMiddleware<S> {
+    service: Rc<S>,
+    requirements: Rc<Vec<(actix_web::http::Method, Requirement)>>,
+    authenticators: Rc<HashMap<&'static str, Authenticator>>,
+}
+
+impl<S, B> Service<ServiceRequest> for SecurityMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = AuthFuture<Self::Response>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let authenticators = Rc::clone(&self.authenticators);
+        let requirement = self
+            .requirements
+            .iter()
+            .find(|(method, _)| method == req.method())
+            .map(|(_, requirement)| *requirement);
+
+        Box::pin(async move {
+            if let Some(requirement) = requirement {
+                if let Err(error) = authorize(req.request(), requirement, &authenticators).await {
+                    return Ok(req.error_response(error).map_into_right_body());
+                }
+            }
+
+            service.call(req).await.map(|res| res.map_into_left_body())
+        })
+    }
+}
+
+/// Extract identity authenticated by security scheme
+///
+/// Fails with `401 Unauthorized` when operation has no requirement with scheme of `A`
+#[derive(Debug, Clone)]
+pub struct Authorized<A>(pub A);
+
+impl<A> Authorized<A> {
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A> std::ops::Deref for Authorized<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A: Clone + 'static> FromRequest for Authorized<A> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, actix_web::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<A>()
+                .cloned()
+                .map(Authorized)
+                .ok_or_else(|| ErrorUnauthorized("Unauthorized")),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Api, Method};
+    use actix_web::{http::StatusCode, test, App, HttpResponse};
+
+    #[derive(Debug, Clone)]
+    struct User(String);
+
+    fn bearer(
+        _: &HttpRequest,
+        credentials: Bearer,
+        scopes: &'static [&'static str],
+    ) -> AuthFuture<User> {
+        let result = match credentials.0.as_str() {
+            "admin" => Ok(User("admin".to_owned())),
+            "reader" if !scopes.contains(&"write") => Ok(User("reader".to_owned())),
+            _ => Err(actix_web::error::ErrorForbidden("Insufficient scope")),
+        };
+
+        Box::pin(ready(result))
+    }
+
+    fn api_key(
+        _: &HttpRequest,
+        credentials: ApiKey,
+        _: &'static [&'static str],
+    ) -> AuthFuture<String> {
+        Box::pin(ready(Ok(credentials.0)))
+    }
+
+    async fn whoami(user: Authorized<User>) -> HttpResponse {
+        HttpResponse::Ok().body(user.0 .0.clone())
+    }
+
+    async fn service_name(key: Authorized<String>) -> HttpResponse {
+        HttpResponse::Ok().body(key.into_inner())
+    }
+
+    fn api() -> Api {
+        Api::new()
+            .security_scheme("bearerAuth", Scheme::Bearer, bearer)
+            .security_scheme(
+                "apiKey",
+                Scheme::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    name: "X-Api-Key",
+                },
+                api_key,
+            )
+            .bind("/whoami", Method::GET, whoami)
+            .security("/whoami", Method::GET, &[&[("bearerAuth", &["read"])]])
+            .bind("/posts", Method::POST, whoami)
+            .security("/posts", Method::POST, &[&[("bearerAuth", &["write"])]])
+            .bind("/service", Method::GET, service_name)
+            .security(
+                "/service",
+                Method::GET,
+                &[&[("bearerAuth", &[]), ("apiKey", &[])], &[("apiKey", &[])]],
+            )
+            .bind("/audit", Method::GET, service_name)
+            .security(
+                "/audit",
+                Method::GET,
+                &[
+                    &[("apiKey", &[]), ("bearerAuth", &["write"])],
+                    &[("bearerAuth", &["read"])],
+                ],
+            )
+    }
+
+    #[actix_rt::test]
+    async fn requirement_checks_scheme_and_scopes() {
+        let app = test::init_service(App::new().service(api())).await;
+
+        let req = test::TestRequest::get().uri("/whoami").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::get()
+            .uri("/whoami")
+            .insert_header((header::AUTHORIZATION, "Bearer reader"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "reader");
+
+        let req = test::TestRequest::post()
+            .uri("/posts")
+            .insert_header((header::AUTHORIZATION, "Bearer reader"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_rt::test]
+    async fn requirement_alternatives() {
+        let app = test::init_service(App::new().service(api())).await;
+
+        let req = test::TestRequest::get()
+            .uri("/service")
+            .insert_header(("X-Api-Key", "billing"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "billing");
+
+        let req = test::TestRequest::get()
+            .uri("/service")
+            .insert_header((header::AUTHORIZATION, "Bearer admin"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_rt::test]
+    async fn failed_alternative_keeps_no_identity() {
+        let app = test::init_service(App::new().service(api())).await;
+
+        // Api key is authenticated in the first alternative, but bearer lacks `write` scope
+        let req = test::TestRequest::get()
+            .uri("/audit")
+            .insert_header(("X-Api-Key", "billing"))
+            .insert_header((header::AUTHORIZATION, "Bearer reader"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::get()
+            .uri("/audit")
+            .insert_header(("X-Api-Key", "billing"))
+            .insert_header((header::AUTHORIZATION, "Bearer admin"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "billing");
+    }
+
+    #[test]
+    fn unauthenticated_schemes_fail_check() {
+        let api = Api::new()
+            .security_scheme("bearerAuth", Scheme::Bearer, bearer)
+            .bind("/service", Method::GET, service_name)
+            .security(
+                "/service",
+                Method::GET,
+                &[&[("bearerAuth", &[]), ("apiKey", &[])], &[("apiKey", &[])]],
+            );
+
+        assert_eq!(api.unauthenticated(), vec!["apiKey"]);
+        assert_eq!(
+            api.check().unwrap_err().to_string(),
+            "Security schemes without authenticator: apiKey"
+        );
+        assert!(crate::Api::new().check().is_ok());
+    }
+
+    #[actix_rt::test]
+    #[should_panic(expected = "Security schemes without authenticator: apiKey")]
+    async fn unauthenticated_scheme_panics_on_register() {
+        let api = Api::new()
+            .bind("/service", Method::GET, service_name)
+            .security("/service", Method::GET, &[&[("apiKey", &[])]]);
+
+        test::init_service(App::new().service(api)).await;
+    }
+
+    #[test]
+    fn basic_credentials() {
+        let req = test::TestRequest::default()
+            .insert_header((header::AUTHORIZATION, "Basic dXNlcjpwYXNz"))
+            .to_http_request();
+
+        let basic = Basic::extract(&req, &Scheme::Basic).unwrap();
+
+        assert_eq!(basic.user_id, "user");
+        assert_eq!(basic.password.as_deref(), Some("pass"));
+    }
+}
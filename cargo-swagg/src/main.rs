@@ -16,6 +16,70 @@ fn main() -> Result<(), Box<dyn std::error::Error + 'static>> {
                 .help("Where to write rust code")
                 .takes_value(true),
         )
+        .arg(
+            clap::Arg::with_name("out-dir")
+                .long("out-dir")
+                .required(false)
+                .conflicts_with("out-file")
+                .help("Directory where to write rust code as module tree with mod.rs, other .rs files in it are removed")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::with_name("mode")
+                .long("mode")
+                .required(false)
+                .help("What to generate from specification")
+                .possible_values(&["server", "client", "both"])
+                .default_value("server")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::with_name("raw")
+                .long("raw")
+                .required(false)
+                .help("Write generated code without formatting"),
+        )
+        .arg(
+            clap::Arg::with_name("handlers")
+                .long("handlers")
+                .required(false)
+                .help("Generate handlers trait and from_handlers constructor of the api"),
+        )
+        .arg(
+            clap::Arg::with_name("embed-spec")
+                .long("embed-spec")
+                .required(false)
+                .help("Embed specification in JSON to serve it with documentation page"),
+        )
+        .arg(
+            clap::Arg::with_name("views")
+                .long("views")
+                .required(false)
+                .help("Generate request and response views for objects with readOnly or writeOnly properties"),
+        )
+        .arg(
+            clap::Arg::with_name("split-by-tag")
+                .long("split-by-tag")
+                .required(false)
+                .help("Generate module with its own api for every tag"),
+        )
+        .arg(
+            clap::Arg::with_name("server")
+                .long("server")
+                .required(false)
+                .help("Index of the server, routes are mounted under its path")
+                .default_value("0")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::with_name("server-variable")
+                .long("server-variable")
+                .required(false)
+                .help("Value of the server variable, as name=value")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
         .get_matches();
 
     let path = opts
@@ -24,7 +88,7 @@ fn main() -> Result<(), Box<dyn std::error::Error + 'static>> {
 
     let path = std::path::Path::new(&path);
 
-    let content = std::fs::read_to_string(&path)?;
+    let content = std::fs::read_to_string(path)?;
 
     let format = match path.extension().and_then(|ext| ext.to_str()) {
         Some("yaml") | Some("yml") | None => swagg::Format::Yaml,
@@ -32,9 +96,51 @@ fn main() -> Result<(), Box<dyn std::error::Error + 'static>> {
         Some(ext) => panic!("Unexpected source extension {}", ext),
     };
 
-    let source_code = swagg::to_string(&content, format).unwrap();
+    let mode = match opts.value_of("mode") {
+        Some("client") => swagg::Mode::Client,
+        Some("both") => swagg::Mode::Both,
+        _ => swagg::Mode::Server,
+    };
+    let server = opts
+        .value_of("server")
+        .unwrap_or("0")
+        .parse()
+        .expect("Server should be an index in servers");
+
+    let server_variables = opts
+        .values_of("server-variable")
+        .map(|values| {
+            values
+                .map(|value| match value.split_once('=') {
+                    Some((name, value)) => (name.to_owned(), value.to_owned()),
+                    None => panic!("Server variable should be name=value, got {}", value),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let settings = swagg::Settings {
+        mode,
+        raw: opts.is_present("raw"),
+        views: opts.is_present("views"),
+        handlers: opts.is_present("handlers"),
+        embed_spec: opts.is_present("embed-spec"),
+        split_by_tag: opts.is_present("split-by-tag"),
+        server,
+        server_variables,
+        base_dir: path.parent().map(std::path::Path::to_path_buf),
+        ..swagg::Settings::default()
+    };
+
+    if let Some(dir) = opts.value_of("out-dir") {
+        let files = swagg::to_files_with(&content, format, &settings)?;
+        swagg::write_files(std::path::Path::new(dir), &files)?;
+
+        return Ok(());
+    }
+
+    let code = swagg::to_string_with(&content, format, &settings).unwrap();
 
-    let code = format!("{}", source_code);
     if let Some(file) = opts.value_of("out-file") {
         std::fs::write(file, code).expect("Failed to write rust code to out file");
     } else {
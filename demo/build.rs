@@ -0,0 +1,6 @@
+fn main() {
+    swagg::Builder::new()
+        .spec("openapi.yaml")
+        .build()
+        .expect("Failed to generate api");
+}
@@ -0,0 +1,70 @@
+//! Include code generated by `swagg` at compile time
+//!
+//! ```ignore
+//! swagg_macro::include_api!("openapi.yaml");
+//! ```
+//!
+//! Path is relative to `CARGO_MANIFEST_DIR` of the crate where macro is called.
+//! Crate is rebuilt when specification or any file referenced from it changes.
+//!
+//! Macro is not re-exported from `swagg`, this crate depends on it.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::{parse_macro_input, LitStr};
+
+/// Expand modules generated from openapi3 specification (yaml or json)
+#[proc_macro]
+pub fn include_api(input: TokenStream) -> TokenStream {
+    let source = parse_macro_input!(input as LitStr);
+
+    match expand(&source) {
+        Ok(tokens) => tokens.into(),
+        Err(message) => syn::Error::new(source.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn expand(source: &LitStr) -> Result<proc_macro2::TokenStream, String> {
+    let path = resolve(&source.value())?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") | None => swagg::Format::Yaml,
+        Some("json") => swagg::Format::Json,
+        Some(ext) => return Err(format!("Unexpected source extension {}", ext)),
+    };
+
+    // Code is parsed right after, there is no need to format it
+    let settings = swagg::Settings {
+        raw: true,
+        base_dir: path.parent().map(Path::to_path_buf),
+        ..swagg::Settings::default()
+    };
+    let code = swagg::to_string_with(&content, format, &settings)
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    let file = syn::parse_file(&code)
+        .map_err(|error| format!("Generated code cannot be parsed: {}", error))?;
+
+    // Every file reached through `$ref` is included, so crate is rebuilt when any of them changes
+    let tracked =
+        swagg::tracked_files(&path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let tracked = tracked.iter().map(|file| file.to_string_lossy());
+
+    Ok(quote! {
+        #(const _: &[u8] = include_bytes!(#tracked);)*
+
+        #file
+    })
+}
+
+fn resolve(source: &str) -> Result<PathBuf, String> {
+    let root = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| "CARGO_MANIFEST_DIR is not set, macro should be called by cargo".to_owned())?;
+
+    Ok(Path::new(&root).join(source))
+}
@@ -0,0 +1,348 @@
+//! # Builder
+//!
+//! Generates code from `build.rs` into `OUT_DIR`
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     swagg::Builder::new()
+//!         .spec("openapi.yaml")
+//!         .build()
+//!         .expect("Failed to generate api");
+//! }
+//!
+//! // src/lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/openapi.rs"));
+//! ```
+
+use crate::{to_string_with, Error, Format, Mode, Resolver, Settings};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+struct Spec {
+    source: PathBuf,
+    file_name: String,
+}
+
+/// Generates code for every spec into its own file in `OUT_DIR`
+#[derive(Default)]
+pub struct Builder {
+    specs: Vec<Spec>,
+    settings: Settings,
+    out_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add spec, code is written to file named after spec, `openapi.yaml` to `openapi.rs`
+    pub fn spec<P: AsRef<Path>>(self, source: P) -> Self {
+        let file_name = source
+            .as_ref()
+            .file_stem()
+            .map(|stem| format!("{}.rs", stem.to_string_lossy()))
+            .unwrap_or_else(|| "api.rs".to_owned());
+
+        self.spec_to(source, file_name)
+    }
+
+    /// Add spec with custom name of the file in `OUT_DIR`
+    pub fn spec_to<P: AsRef<Path>, N: Into<String>>(mut self, source: P, file_name: N) -> Self {
+        self.specs.push(Spec {
+            source: source.as_ref().to_path_buf(),
+            file_name: file_name.into(),
+        });
+        self
+    }
+
+    /// Replace all settings at once
+    pub fn settings(mut self, settings: Settings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.settings.mode = mode;
+        self
+    }
+
+    /// Name of the api struct instead of `info.title`
+    pub fn api_name<N: Into<String>>(mut self, name: N) -> Self {
+        self.settings.api_name = Some(name.into());
+        self
+    }
+
+    /// Lints allowed for every generated module, `dead_code` and `unused_imports` by default
+    pub fn lints<I, L>(mut self, lints: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        self.settings.lints = lints.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Write generated code without formatting
+    pub fn raw(mut self, raw: bool) -> Self {
+        self.settings.raw = raw;
+        self
+    }
+
+    /// Generate `<Name>Request` and `<Name>Response` views for objects with `readOnly` or `writeOnly` properties
+    pub fn views(mut self, views: bool) -> Self {
+        self.settings.views = views;
+        self
+    }
+
+    /// Generate `<Api>Handlers` trait, api created by `from_handlers` has route for every operation
+    pub fn handlers(mut self, handlers: bool) -> Self {
+        self.settings.handlers = handlers;
+        self
+    }
+
+    /// Embed specification in JSON, generated api serves it with `openapi` and `docs`
+    pub fn embed_spec(mut self, embed_spec: bool) -> Self {
+        self.settings.embed_spec = embed_spec;
+        self
+    }
+
+    /// Put operations of every tag into own module with api that can be mounted on its own
+    pub fn split_by_tag(mut self, split_by_tag: bool) -> Self {
+        self.settings.split_by_tag = split_by_tag;
+        self
+    }
+
+    /// Mount routes under path of the server with `index` in `servers`, the first one by default
+    pub fn server(mut self, index: usize) -> Self {
+        self.settings.server = index;
+        self
+    }
+
+    /// Value of the server variable instead of its `default`
+    pub fn server_variable<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.settings
+            .server_variables
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Loads documents for remote `$ref`, only local files are resolved without it
+    pub fn resolver<R: Resolver + 'static>(mut self, resolver: R) -> Self {
+        self.settings.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Directory for generated files, `OUT_DIR` by default
+    pub fn out_dir<P: AsRef<Path>>(mut self, out_dir: P) -> Self {
+        self.out_dir = Some(out_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Generate formatted code for every spec and tell cargo to rerun when any spec file changes
+    ///
+    /// Returns paths of the written files
+    pub fn build(self) -> Result<Vec<PathBuf>, Error> {
+        let out_dir = match self.out_dir {
+            Some(out_dir) => out_dir,
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(Error::OutDirNotSet)?,
+        };
+
+        let mut written = vec![];
+
+        for spec in self.specs.iter() {
+            for tracked in tracked_files(&spec.source)? {
+                println!("cargo:rerun-if-changed={}", tracked.display());
+            }
+
+            let content = read(&spec.source)?;
+            let format = match spec.source.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => Format::Json,
+                _ => Format::Yaml,
+            };
+
+            // References to other files are relative to the spec by default
+            let base_dir = self.settings.base_dir.clone();
+            let settings = Settings {
+                base_dir: base_dir.or_else(|| spec.source.parent().map(Path::to_path_buf)),
+                ..self.settings.clone()
+            };
+            let code = to_string_with(&content, format, &settings)?;
+
+            let target = out_dir.join(&spec.file_name);
+            std::fs::write(&target, code).map_err(|error| Error::Io(target.clone(), error))?;
+
+            written.push(target);
+        }
+
+        Ok(written)
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|error| Error::Io(path.to_path_buf(), error))
+}
+
+/// Spec file and every local file reached through `$ref`, each file once
+///
+/// Generated code depends on all of them, so they are tracked to rebuild when any file changes
+pub fn tracked_files(source: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut visited = BTreeSet::new();
+    let mut queue = vec![normalize(source)];
+
+    while let Some(path) = queue.pop() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(&read(&path)?).map_err(|_| Error::InvalidSource)?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut references = vec![];
+        collect_references(&value, &mut references);
+
+        for reference in references {
+            let file = reference.split('#').next().unwrap_or_default();
+
+            // Local and remote references are not files on disk
+            if file.is_empty() || file.contains("://") {
+                continue;
+            }
+
+            queue.push(normalize(&base.join(file)));
+        }
+    }
+
+    Ok(visited.into_iter().collect())
+}
+
+/// Remove `.` and resolve `..` without touching file system, so the same file has the same path
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+
+    normalized
+}
+
+fn collect_references(value: &serde_yaml::Value, references: &mut Vec<String>) {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Mapping(mapping) => {
+            for (key, value) in mapping.iter() {
+                match (key.as_str(), value.as_str()) {
+                    (Some("$ref"), Some(reference)) => references.push(reference.to_owned()),
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        Value::Sequence(sequence) => {
+            for value in sequence.iter() {
+                collect_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      $ref: "./models/user.yaml#/User"
+    Remote:
+      $ref: "https://example.com/remote.yaml#/Remote"
+    Local:
+      $ref: "#/components/schemas/User"
+"###;
+
+    const USER: &str = r###"
+User:
+  type: object
+  properties:
+    group:
+      $ref: "./group.yaml#/Group"
+"###;
+
+    // Circular reference to check that every file is visited once
+    const GROUP: &str = r###"
+Group:
+  type: object
+  properties:
+    owner:
+      $ref: "./user.yaml#/User"
+"###;
+
+    #[test]
+    fn tracks_files_reached_through_refs() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        std::fs::create_dir(dir.path().join("models")).unwrap();
+        std::fs::write(dir.path().join("openapi.yaml"), SPEC).unwrap();
+        std::fs::write(dir.path().join("models/user.yaml"), USER).unwrap();
+        std::fs::write(dir.path().join("models/group.yaml"), GROUP).unwrap();
+
+        let tracked = tracked_files(&dir.path().join("openapi.yaml")).unwrap();
+
+        assert_eq!(
+            tracked,
+            vec![
+                dir.path().join("models/group.yaml"),
+                dir.path().join("models/user.yaml"),
+                dir.path().join("openapi.yaml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_every_spec_to_out_dir() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        let spec = "openapi: 3.0.1\ninfo:\n  title: Demo API.\n  version: 0.1.0\npaths: {}\n";
+        std::fs::write(dir.path().join("first.yaml"), spec).unwrap();
+        std::fs::write(dir.path().join("second.yaml"), spec).unwrap();
+
+        let written = Builder::new()
+            .spec(dir.path().join("first.yaml"))
+            .spec_to(dir.path().join("second.yaml"), "renamed.rs")
+            .api_name("Renamed")
+            .out_dir(dir.path())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            written,
+            vec![dir.path().join("first.rs"), dir.path().join("renamed.rs")]
+        );
+
+        let code = std::fs::read_to_string(dir.path().join("renamed.rs")).unwrap();
+        assert!(code.contains("pub struct Renamed"));
+        // Inner attributes cannot be `include!`d
+        assert!(!code.contains("#!["));
+    }
+}
@@ -0,0 +1,435 @@
+//! Documents reached through `$ref`, each one is loaded once
+
+use super::{ParseSchemaError, Reference};
+use crate::{builder::normalize, Resolver};
+use openapiv3::{ReferenceOr, Schema};
+use serde_yaml::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Document where schema is declared, relative references are resolved against it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Document {
+    /// Specification passed to swagg
+    Root,
+    File(PathBuf),
+    Remote(String),
+}
+
+/// Place of the schema in some document
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    pub document: Document,
+
+    /// JSON pointer without leading `#`
+    pub pointer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Documents {
+    base_dir: PathBuf,
+    resolver: Option<Arc<dyn Resolver>>,
+    cache: HashMap<Document, Value>,
+}
+
+impl Documents {
+    /// Files are resolved against `base_dir`, remote documents are loaded only with `resolver`
+    pub fn new(root: Value, base_dir: PathBuf, resolver: Option<Arc<dyn Resolver>>) -> Self {
+        let mut cache = HashMap::new();
+        cache.insert(Document::Root, root);
+
+        Self {
+            base_dir,
+            resolver,
+            cache,
+        }
+    }
+
+    /// Where reference from the document points to
+    pub fn locate(&self, from: &Document, reference: &Reference) -> Location {
+        let (document, pointer) = match reference {
+            Reference::Relative(relative) => (from.clone(), relative.pointer()),
+            Reference::Remote { url, pointer } => (Document::Remote(url.clone()), pointer.clone()),
+            Reference::File { path, pointer } => {
+                let document = match from {
+                    Document::Root => Document::File(normalize(&self.base_dir.join(path))),
+                    Document::File(file) => {
+                        let base = file.parent().unwrap_or_else(|| Path::new(""));
+
+                        Document::File(normalize(&base.join(path)))
+                    }
+                    Document::Remote(url) => Document::Remote(join_url(url, path)),
+                };
+
+                (document, pointer.clone())
+            }
+        };
+
+        Location { document, pointer }
+    }
+
+    /// Find schema by location, references to references are followed until the schema itself
+    ///
+    /// Returns location where schema is actually declared
+    pub fn schema(&mut self, location: Location) -> Result<(Location, Schema), ParseSchemaError> {
+        let mut visited = BTreeSet::new();
+        let mut location = location;
+
+        loop {
+            if !visited.insert(location.clone()) {
+                return Err(ParseSchemaError::CircularReference(location.to_string()));
+            }
+
+            let value = self.load(&location.document)?;
+            let value = find(value, &location.pointer).ok_or_else(|| {
+                ParseSchemaError::UnresolvedReference(format!("{} is not found", location))
+            })?;
+
+            let schema: ReferenceOr<Schema> =
+                serde_yaml::from_value(value.clone()).map_err(|error| {
+                    ParseSchemaError::UnresolvedReference(format!("{}: {}", location, error))
+                })?;
+
+            match schema {
+                ReferenceOr::Item(schema) => return Ok((location, schema)),
+                ReferenceOr::Reference { reference } => {
+                    location = self.locate(&location.document, &Reference::parse(&reference));
+                }
+            }
+        }
+    }
+
+    /// Specification with every value reached through `$ref` to other documents inlined,
+    /// so it is complete without files and remote documents it refers to
+    ///
+    /// References inside of the root document are kept as written
+    pub fn bundle(&self, root: &Value) -> Result<Value, ParseSchemaError> {
+        let mut loaded = HashMap::new();
+        loaded.insert(Document::Root, root.clone());
+
+        self.inline(root, &Document::Root, &mut loaded, &mut vec![])
+    }
+
+    fn inline(
+        &self,
+        value: &Value,
+        document: &Document,
+        loaded: &mut HashMap<Document, Value>,
+        stack: &mut Vec<Location>,
+    ) -> Result<Value, ParseSchemaError> {
+        match value {
+            Value::Mapping(mapping) => {
+                if let Some(reference) = mapping.get("$ref").and_then(Value::as_str) {
+                    let location = self.locate(document, &Reference::parse(reference));
+
+                    if location.document == Document::Root {
+                        return Ok(value.clone());
+                    }
+
+                    if stack.contains(&location) {
+                        return Err(ParseSchemaError::CircularReference(location.to_string()));
+                    }
+
+                    if !loaded.contains_key(&location.document) {
+                        let target = self.read(&location.document)?;
+                        loaded.insert(location.document.clone(), target);
+                    }
+
+                    let target = find(&loaded[&location.document], &location.pointer)
+                        .cloned()
+                        .ok_or_else(|| {
+                            ParseSchemaError::UnresolvedReference(format!(
+                                "{} is not found",
+                                location
+                            ))
+                        })?;
+
+                    stack.push(location.clone());
+                    let inlined = self.inline(&target, &location.document, loaded, stack);
+                    stack.pop();
+
+                    return inlined;
+                }
+
+                let mut inlined = serde_yaml::Mapping::new();
+                for (key, value) in mapping.iter() {
+                    inlined.insert(key.clone(), self.inline(value, document, loaded, stack)?);
+                }
+
+                Ok(Value::Mapping(inlined))
+            }
+            Value::Sequence(sequence) => sequence
+                .iter()
+                .map(|value| self.inline(value, document, loaded, stack))
+                .collect::<Result<_, _>>()
+                .map(Value::Sequence),
+            value => Ok(value.clone()),
+        }
+    }
+
+    fn load(&mut self, document: &Document) -> Result<&Value, ParseSchemaError> {
+        if !self.cache.contains_key(document) {
+            let mut value = self.read(document)?;
+
+            rewrite_const(&mut value, false);
+
+            self.cache.insert(document.clone(), value);
+        }
+
+        Ok(&self.cache[document])
+    }
+
+    /// Document as written, without any rewrite
+    fn read(&self, document: &Document) -> Result<Value, ParseSchemaError> {
+        let content = match document {
+            Document::Root => unreachable!("root document is always cached"),
+            Document::File(path) => {
+                std::fs::read_to_string(path).map_err(|error| error.to_string())
+            }
+            Document::Remote(url) => match &self.resolver {
+                Some(resolver) => resolver.resolve(url).map_err(|error| error.to_string()),
+                None => Err("remote references require resolver".to_owned()),
+            },
+        };
+
+        content
+            .and_then(|content| serde_yaml::from_str(&content).map_err(|e| e.to_string()))
+            .map_err(|error| {
+                ParseSchemaError::UnresolvedReference(format!("{}: {}", document, error))
+            })
+    }
+}
+
+impl Location {
+    /// Name of the type generated for the schema, last segment of the pointer or name of the file
+    pub fn type_name(&self) -> String {
+        match self.pointer.rsplit('/').next() {
+            Some(segment) if !segment.is_empty() => unescape(segment),
+            _ => {
+                let name = match &self.document {
+                    Document::Root => "",
+                    Document::File(path) => path.to_str().unwrap_or_default(),
+                    Document::Remote(url) => url.as_str(),
+                };
+                let file = name.rsplit('/').next().unwrap_or_default();
+
+                file.split('.').next().unwrap_or_default().to_owned()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Document::Root => Ok(()),
+            Document::File(path) => write!(f, "{}", path.display()),
+            Document::Remote(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}#{}", self.document, self.pointer)
+    }
+}
+
+/// Extension with value of boolean `const`, openapiv3 keeps no `enum` for booleans
+pub const CONST_EXTENSION: &str = "x-const";
+
+/// Keywords whose values are data, not schemas, `const` in them is left as is
+const DATA_KEYWORDS: &[&str] = &["const", "default", "enum", "example", "examples"];
+
+/// Keywords whose keys are names, `properties` or `schemas` for example
+const NAMED_KEYWORDS: &[&str] = &[
+    "callbacks",
+    "content",
+    "encoding",
+    "headers",
+    "links",
+    "parameters",
+    "paths",
+    "properties",
+    "requestBodies",
+    "responses",
+    "schemas",
+    "securitySchemes",
+];
+
+/// openapiv3 drops `const`, so `const: 1` is rewritten to `type: integer` with `enum: [1]`,
+/// `const: true` to `type: boolean` with `x-const: true`
+///
+/// Keys of `properties` are names of the fields, property named `const` is left as is,
+/// as well as `example`, `default` and extension values
+pub fn rewrite_const(value: &mut Value, names: bool) {
+    match value {
+        Value::Mapping(mapping) => {
+            let constant = match names {
+                true => None,
+                false => mapping.get("const").cloned(),
+            };
+            let schema_type = match &constant {
+                Some(Value::String(_)) => Some("string"),
+                Some(Value::Number(number)) if number.is_f64() => Some("number"),
+                Some(Value::Number(_)) => Some("integer"),
+                Some(Value::Bool(_)) => Some("boolean"),
+                _ => None,
+            };
+
+            if let (Some(constant), Some(schema_type)) = (constant, schema_type) {
+                mapping.remove("const");
+
+                match constant {
+                    Value::Bool(_) => mapping.insert(CONST_EXTENSION.into(), constant),
+                    constant => mapping.insert("enum".into(), Value::Sequence(vec![constant])),
+                };
+
+                if !mapping.contains_key("type") {
+                    mapping.insert("type".into(), schema_type.into());
+                }
+            }
+
+            for (key, value) in mapping.iter_mut() {
+                let key = key.as_str().unwrap_or_default();
+
+                match names {
+                    true => rewrite_const(value, false),
+                    false if key.starts_with("x-") || DATA_KEYWORDS.contains(&key) => {}
+                    false => rewrite_const(value, NAMED_KEYWORDS.contains(&key)),
+                }
+            }
+        }
+        Value::Sequence(sequence) => {
+            for value in sequence.iter_mut() {
+                rewrite_const(value, false);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolve value by JSON pointer
+fn find<'a>(value: &'a Value, pointer: &str) -> Option<&'a Value> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(unescape)
+        .try_fold(value, |value, segment| match value {
+            Value::Mapping(mapping) => mapping
+                .iter()
+                .find(|(key, _)| match key {
+                    Value::String(key) => *key == segment,
+                    Value::Number(key) => key.to_string() == segment,
+                    _ => false,
+                })
+                .map(|(_, value)| value),
+            Value::Sequence(sequence) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| sequence.get(index)),
+            _ => None,
+        })
+}
+
+fn unescape(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// Relative url from the remote document, `./` and `../` are resolved
+fn join_url(base: &str, path: &str) -> String {
+    let mut url = base
+        .rsplit_once('/')
+        .map_or(base, |(dir, _)| dir)
+        .to_owned();
+
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." => {
+                if let Some((dir, _)) = url.rsplit_once('/') {
+                    if !dir.ends_with('/') {
+                        url.truncate(dir.len());
+                    }
+                }
+            }
+            segment => {
+                url.push('/');
+                url.push_str(segment);
+            }
+        }
+    }
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_circular_references() {
+        let root: Value =
+            serde_yaml::from_str("A:\n  $ref: '#/B'\nB:\n  $ref: '#/A'\nC:\n  $ref: '#/A'\n")
+                .unwrap();
+        let mut documents = Documents::new(root, PathBuf::new(), None);
+
+        let result = documents.schema(Location {
+            document: Document::Root,
+            pointer: "/C".to_owned(),
+        });
+
+        assert!(
+            matches!(result, Err(ParseSchemaError::CircularReference(location)) if location == "#/A")
+        );
+    }
+
+    #[test]
+    fn rewrites_const_to_enum() {
+        let mut value: Value = serde_yaml::from_str(
+            "properties:\n  const:\n    const: 2\n  kind:\n    type: string\n    const: user\n",
+        )
+        .unwrap();
+
+        rewrite_const(&mut value, false);
+
+        let expected: Value = serde_yaml::from_str(
+            "properties:\n  const:\n    enum: [2]\n    type: integer\n  kind:\n    type: string\n    enum: [user]\n",
+        )
+        .unwrap();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn rewrites_const_of_schemas_only() {
+        let source = "type: object\nexample:\n  const: 1\nx-meta:\n  const: 1\nproperties:\n  flag:\n    const: true\n    default: {const: 1}\n";
+        let mut value: Value = serde_yaml::from_str(source).unwrap();
+
+        rewrite_const(&mut value, false);
+
+        let expected: Value = serde_yaml::from_str(
+            "type: object\nexample:\n  const: 1\nx-meta:\n  const: 1\nproperties:\n  flag:\n    x-const: true\n    type: boolean\n    default: {const: 1}\n",
+        )
+        .unwrap();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn resolves_relative_urls() {
+        let base = "https://example.com/api/models/user.yaml";
+
+        assert_eq!(
+            join_url(base, "./group.yaml"),
+            "https://example.com/api/models/group.yaml"
+        );
+        assert_eq!(
+            join_url(base, "../../common.yaml"),
+            "https://example.com/common.yaml"
+        );
+        assert_eq!(
+            join_url(base, "../../../x.yaml"),
+            "https://example.com/x.yaml"
+        );
+    }
+}
@@ -3,61 +3,136 @@
 //! This module is a database of components and paths
 //! Useful when converting OpenAPI structures to printer structures
 
-use crate::printer;
+mod documents;
+mod names;
+mod paths;
+mod servers;
+
+pub use documents::{rewrite_const, Document, Documents, Location};
+pub use names::Names;
+pub use paths::Operation;
+pub use servers::server_path;
+
+use crate::{printer, Mode};
 use indexmap::IndexMap;
 use openapiv3::{ReferenceOr, Schema};
 
 /// List of components ready to be printed
 #[derive(Debug, Default)]
 pub struct Components {
-    pub request_bodies: IndexMap<String, RequestBody>,
+    pub parameters: IndexMap<String, Component>,
+    pub request_bodies: IndexMap<String, Component>,
+    pub responses: IndexMap<String, Component>,
     pub schemas: IndexMap<String, Component>,
-}
+    pub security_schemes: IndexMap<String, SecurityScheme>,
 
-#[derive(Debug, Default, PartialEq, Eq)]
-pub struct RequestBody {
-    pub name: String,
-    pub required: bool,
-    pub description: Option<String>,
-    pub fields: IndexMap<String, ComponentField>,
+    /// Operations of `paths`, in order of the specification
+    pub operations: Vec<Operation>,
+
+    /// Files and remote documents reached through `$ref`
+    documents: Documents,
+
+    /// Names of the types for schemas from other documents
+    external: IndexMap<Location, String>,
+
+    /// External schemas referenced but not parsed yet
+    pending: Vec<Location>,
+
+    /// Every generated type gets unique name
+    names: Names,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq)]
 pub struct Component {
     pub name: String,
     pub description: Option<String>,
     pub kind: ComponentKind,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Default, PartialEq, Clone)]
 pub enum ComponentKind {
     Object {
         fields: IndexMap<String, ComponentField>,
     },
+    /// Values of the same type from `enum`
+    Enum {
+        variants: Vec<EnumVariant>,
+    },
+    /// Single-value `enum` or `const`
+    Const {
+        value: EnumValue,
+    },
     Array {
         items: FieldType,
     },
     Integer,
+    #[default]
     String,
     Number,
     Boolean,
+    /// Type of other module, types of `parameters`, `request_bodies` and `responses` refer to `schemas`
+    Alias {
+        target: FieldType,
+    },
 }
 
-impl Default for ComponentKind {
-    fn default() -> Self {
-        Self::String
-    }
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnumVariant {
+    /// Name from `x-enum-varnames`
+    pub name: Option<String>,
+    pub value: EnumValue,
 }
 
-#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum EnumValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+
+    /// Only from `const`, see [`documents::CONST_EXTENSION`]
+    Boolean(bool),
+}
+
+/// Extension with names of the enum variants, in the same order as values
+const ENUM_VARNAMES_EXTENSION: &str = "x-enum-varnames";
+
+#[derive(Debug, Default, PartialEq, Clone)]
 pub struct ComponentField {
     pub required: bool,
     pub description: Option<String>,
     pub field_type: FieldType,
+
+    /// Value from `default`, checked against the field type before printing
+    pub default: Option<DefaultValue>,
+
+    /// Property is sent only in responses, `readOnly`
+    pub read_only: bool,
+
+    /// Property is sent only in requests, `writeOnly`
+    pub write_only: bool,
+
+    /// Side which must not send the property never serializes it
+    pub skip_serializing: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DefaultValue {
+    Value(EnumValue),
+    Boolean(bool),
+
+    /// Variant of the enum, by the name of the variant type
+    Variant(String),
+
+    /// Non-empty list of scalar values
+    List(Vec<DefaultValue>),
+
+    /// Empty list or object, or marker type
+    Empty,
 }
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
 pub enum FieldType {
+    #[default]
     String,
     Number,
     Integer,
@@ -65,269 +140,1130 @@ pub enum FieldType {
 
     /// Name of the type in module
     Type(String),
+
+    /// List of items, has a known size even for recursive types
+    Array(Box<FieldType>),
+
+    /// Type placed on the heap to break recursion
+    Boxed(Box<FieldType>),
 }
 
-impl Default for FieldType {
-    fn default() -> Self {
-        Self::String
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SecurityScheme {
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: SecuritySchemeKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SecuritySchemeKind {
+    Bearer,
+    Basic,
+    ApiKey {
+        location: ApiKeyLocation,
+        name: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
+
+impl FieldType {
+    /// The same type with names of the types replaced from `names`
+    fn renamed(&self, names: &IndexMap<String, String>) -> Self {
+        match self {
+            Self::Type(name) => Self::Type(names.get(name).unwrap_or(name).clone()),
+            Self::Array(inner) => Self::Array(Box::new(inner.renamed(names))),
+            Self::Boxed(inner) => Self::Boxed(Box::new(inner.renamed(names))),
+            other => other.clone(),
+        }
     }
 }
 
 impl Components {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn with_documents(documents: Documents) -> Self {
+        Self {
+            documents,
+            ..Self::default()
+        }
     }
 
-    fn insert_request_body(&mut self, body: RequestBody) {
-        self.request_bodies.insert(body.name.clone(), body);
+    /// Objects with `readOnly` or `writeOnly` properties get two more types:
+    /// `<Name>Request` without read-only and `<Name>Response` without write-only properties
+    ///
+    /// Objects containing such objects get views too, fields of the views refer to views of the same side
+    pub fn split_views(&mut self) {
+        let mut viewed: Vec<String> = self
+            .schemas
+            .values()
+            .filter(|component| match &component.kind {
+                ComponentKind::Object { fields } => fields
+                    .values()
+                    .any(|field| field.read_only || field.write_only),
+                _ => false,
+            })
+            .map(|component| component.name.clone())
+            .collect();
+
+        loop {
+            let containing: Vec<String> = self
+                .schemas
+                .values()
+                .filter(|component| !viewed.contains(&component.name))
+                .filter(|component| match &component.kind {
+                    ComponentKind::Object { fields } => fields
+                        .values()
+                        .any(|field| refers_to_any(&field.field_type, &viewed)),
+                    _ => false,
+                })
+                .map(|component| component.name.clone())
+                .collect();
+
+            if containing.is_empty() {
+                break;
+            }
+
+            viewed.extend(containing);
+        }
+
+        let mut requests = IndexMap::new();
+        let mut responses = IndexMap::new();
+        for name in viewed.iter() {
+            requests.insert(name.clone(), self.names.unique(format!("{}Request", name)));
+            responses.insert(name.clone(), self.names.unique(format!("{}Response", name)));
+        }
+
+        let schemas = std::mem::take(&mut self.schemas);
+
+        for (key, component) in schemas.into_iter() {
+            let views = match requests.get(&component.name) {
+                Some(_) => {
+                    let request = view(&component, "Request", "read-only", &requests, |field| {
+                        !field.read_only
+                    });
+                    let response =
+                        view(&component, "Response", "write-only", &responses, |field| {
+                            !field.write_only
+                        });
+
+                    vec![request, response]
+                }
+                None => vec![],
+            };
+
+            self.schemas.insert(key, component);
+
+            for view in views {
+                self.schemas.insert(view.name.clone(), view);
+            }
+        }
+    }
+
+    /// Property which is not sent by the other side is optional,
+    /// property which must not be sent by this side is never serialized
+    ///
+    /// Server receives requests and sends responses, client does the opposite
+    pub fn apply_access(&mut self, mode: Mode) {
+        for component in self.schemas.values_mut() {
+            if let ComponentKind::Object { fields } = &mut component.kind {
+                for field in fields.values_mut() {
+                    let (not_received, not_sent) = match mode {
+                        Mode::Server => (field.read_only, field.write_only),
+                        Mode::Client => (field.write_only, field.read_only),
+                        // Server must not leak `writeOnly`, client sends it with `<Name>Request` view
+                        Mode::Both => (field.read_only || field.write_only, field.write_only),
+                    };
+
+                    if not_received {
+                        field.required = false;
+                    }
+
+                    field.skip_serializing = not_sent;
+                }
+            }
+        }
     }
 
     fn insert_schema(&mut self, component: Component) {
         self.schemas.insert(component.name.clone(), component);
     }
 
+    /// Reserve type name for schema from `components.schemas`
+    ///
+    /// Every schema should be declared before parsing,
+    /// so inline types never take names of the declared ones
+    pub fn declare_schema(&mut self, name: &str, schema: &ReferenceOr<Schema>) {
+        let data = match schema {
+            ReferenceOr::Item(schema) => Some(&schema.schema_data),
+            ReferenceOr::Reference { .. } => None,
+        };
+
+        self.names.declare(name, data);
+    }
+
     pub fn parse_schema(
         &mut self,
         name: &String,
         schema: &ReferenceOr<Schema>,
     ) -> Result<(), ParseSchemaError> {
-        let (_, created_components) = parse_ref_or_schema(&name, &schema)?;
+        let type_name = self.names.declared(name);
+
+        if let ReferenceOr::Reference { reference } = schema {
+            let reference = Reference::parse(reference);
+
+            if let Reference::Relative(_) = reference {
+                log::info!(
+                    "aliases for schemas are not supported yet. Skipping {}...",
+                    name
+                );
+
+                return Err(ParseSchemaError::ReferenceNotSupported);
+            }
+
+            // Schema from other document is generated right under the component name
+            let location = self.documents.locate(&Document::Root, &reference);
+            let registered = self.register(location, Some(type_name.clone()));
+
+            if registered != type_name {
+                log::info!("{} is already generated as {}", name, registered);
+            }
+
+            return self.parse_pending();
+        }
 
-        for component in created_components.into_iter() {
+        let (field_type, created_components) =
+            self.parse_ref_or_schema(&Document::Root, &type_name, schema)?;
+        let description = match schema {
+            ReferenceOr::Item(schema) => schema.schema_data.description.clone(),
+            ReferenceOr::Reference { .. } => None,
+        };
+        let component = named_component(&type_name, description, field_type);
+
+        for component in component.into_iter().chain(created_components) {
             self.insert_schema(component);
         }
 
+        self.parse_pending()
+    }
+
+    /// Remember schema from other document, it is parsed by [`Self::parse_pending`]
+    ///
+    /// Returns name of the type, each schema gets the name once
+    fn register(&mut self, location: Location, name: Option<String>) -> String {
+        use inflections::Inflect;
+
+        if let Some(type_name) = self.external.get(&location) {
+            return type_name.clone();
+        }
+
+        let type_name = match name {
+            Some(name) => name,
+            None => self.names.unique(location.type_name().to_pascal_case()),
+        };
+
+        self.external.insert(location.clone(), type_name.clone());
+        self.pending.push(location);
+
+        type_name
+    }
+
+    /// Parse every schema reached through references to other documents
+    fn parse_pending(&mut self) -> Result<(), ParseSchemaError> {
+        while let Some(location) = self.pending.pop() {
+            let type_name = self.external[&location].clone();
+            let (declared, schema) = self.documents.schema(location)?;
+            let (field_type, created_components) =
+                self.parse_schema_kind(&declared.document, &type_name, &schema)?;
+            let description = schema.schema_data.description.clone();
+            let component = named_component(&type_name, description, field_type);
+
+            for component in component.into_iter().chain(created_components) {
+                self.insert_schema(component);
+            }
+        }
+
         Ok(())
     }
 
-    // Parse request body from openapiv3 and add it to index
-    // pub fn parse_request_body(&mut self, name: &String, request_body: &openapiv3::RequestBody) {
-    //     log::trace!("Parsing request body {}", name);
-    //     let mut highway_body = RequestBody::default();
-
-    //     highway_body.name = name.clone();
-    //     highway_body.description = request_body.description.clone();
-    //     highway_body.required = request_body.required;
-
-    //     if let Some(media_type) = request_body.content.get("application/json") {
-    //         if let Some(ReferenceOr::Item(schema)) = media_type.schema.clone() {
-    //             self.parse_body_schema(&schema);
-    //         } else {
-    //             log::info!(
-    //                 "skipping media type {:?} in {}.content, supported only inline schema",
-    //                 media_type.schema,
-    //                 name
-    //             );
-    //         }
-    //     } else {
-    //         log::info!(
-    //             "skipping content {:?} in {}, supported only application/json",
-    //             request_body.content,
-    //             name
-    //         );
-    //     }
-
-    //     self.insert_request_body(highway_body);
-    // }
-
-    // fn parse_body_schema(&mut self, schema: &openapiv3::Schema) {
-    //     log::trace!("Parsing schema {:?}", schema);
-    //     use openapiv3::{SchemaKind, Type};
-
-    //     match schema.schema_kind.clone() {
-    //         SchemaKind::Type(internal_type) => match internal_type {
-    //             Type::Object(object) => {
-    //                 let component = Self::parse_object_to_component(&object);
-    //                 let body = RequestBody {
-    //                     name: component.name,
-    //                     description: component.description,
-    //                     required: true,
-    //                     fields: component.fields,
-    //                 };
-    //                 self.insert_request_body(body);
-    //             }
-    //             unsupported_type => {
-    //                 log::info!("unsupported schema type {:?}", unsupported_type);
-    //             }
-    //         },
-    //         unsupported_kind => {
-    //             log::info!("unsupported schema kind {:?}", unsupported_kind);
-    //         }
-    //     }
-    // }
-
-    // Can't parse description and name
-    // fn parse_object_to_component(object: &openapiv3::ObjectType) -> Component {
-    //     use openapiv3::{SchemaKind, Type};
-    //     let mut component = Component::default();
-
-    //     for (name, schema) in object.properties.iter() {
-    //         let required = object.required.iter().find(|e| *e == name).is_some();
-
-    //         if let ReferenceOr::Item(schema) = schema.clone().unbox() {
-    //             match schema.schema_kind {
-    //                 SchemaKind::Type(Type::String(_string_type)) => {
-    //                     let field_type = FieldType::String;
-    //                     let field = ComponentField {
-    //                         name: name.clone(),
-    //                         description: schema.schema_data.description.clone(),
-    //                         required,
-    //                         field_type,
-    //                     };
-    //                     component.fields.insert(name.clone(), field);
-    //                 }
-    //                 unsupported_kind => {
-    //                     log::info!("skipping unsupported kind {:?}", unsupported_kind);
-    //                     continue;
-    //                 }
-    //             }
-    //         } else {
-    //             log::info!("skipping property {} due to unsupported refs", name);
-    //         }
-    //     }
-
-    //     component
-    // }
+    pub fn parse_security_scheme(
+        &mut self,
+        name: &String,
+        scheme: &ReferenceOr<openapiv3::SecurityScheme>,
+    ) -> Result<(), ParseSecuritySchemeError> {
+        use openapiv3::{APIKeyLocation, SecurityScheme as Scheme};
+
+        let scheme = match scheme {
+            ReferenceOr::Item(scheme) => scheme,
+            ReferenceOr::Reference { reference } => {
+                log::info!(
+                    "reference for security schemes is not supported yet. Skipping {} for {}...",
+                    reference,
+                    name
+                );
+
+                return Err(ParseSecuritySchemeError::ReferenceNotSupported);
+            }
+        };
+
+        let (description, kind) = match scheme {
+            Scheme::APIKey {
+                location,
+                name,
+                description,
+                ..
+            } => {
+                let location = match location {
+                    APIKeyLocation::Header => ApiKeyLocation::Header,
+                    APIKeyLocation::Query => ApiKeyLocation::Query,
+                    APIKeyLocation::Cookie => ApiKeyLocation::Cookie,
+                };
+                let kind = SecuritySchemeKind::ApiKey {
+                    location,
+                    name: name.clone(),
+                };
+
+                (description, kind)
+            }
+            Scheme::HTTP {
+                scheme,
+                description,
+                ..
+            } => match scheme.to_lowercase().as_str() {
+                "bearer" => (description, SecuritySchemeKind::Bearer),
+                "basic" => (description, SecuritySchemeKind::Basic),
+                other => {
+                    log::info!(
+                        "http scheme {} is not supported. Skipping {}...",
+                        other,
+                        name
+                    );
+
+                    return Err(ParseSecuritySchemeError::UnsupportedScheme);
+                }
+            },
+            // Access tokens of both are passed as bearer
+            Scheme::OAuth2 { description, .. } => (description, SecuritySchemeKind::Bearer),
+            Scheme::OpenIDConnect { description, .. } => (description, SecuritySchemeKind::Bearer),
+        };
+
+        self.security_schemes.insert(
+            name.clone(),
+            SecurityScheme {
+                name: name.clone(),
+                description: description.clone(),
+                kind,
+            },
+        );
+
+        Ok(())
+    }
 }
 
+#[derive(Debug, PartialEq, Eq)]
 pub enum Reference {
     /// References to the current file
     Relative(ReferenceRelative),
 
     /// References to the file on the current disk
-    File(String),
+    File { path: String, pointer: String },
 
     /// References to the file in the web
-    Remote,
+    Remote { url: String, pointer: String },
 }
 
+/// Place in the current file, `Pointer` for anything outside of components
+#[derive(Debug, PartialEq, Eq)]
 pub enum ReferenceRelative {
-    Response { name: String },
-    Parameter { name: String },
-    RequestBody { name: String },
-    Schema { name: String },
-    Responses { name: String },
+    Response {
+        name: String,
+    },
+    Parameter {
+        name: String,
+    },
+    RequestBody {
+        name: String,
+    },
+    Schema {
+        name: String,
+    },
+    Header {
+        name: String,
+    },
+    #[allow(dead_code)]
+    Responses {
+        name: String,
+    },
+    Pointer {
+        pointer: String,
+    },
+}
+
+impl Reference {
+    /// Parse `$ref` value, `./models/user.yaml#/User` or `#/components/schemas/User`
+    pub fn parse(reference: &str) -> Self {
+        let (location, pointer) = match reference.split_once('#') {
+            Some((location, pointer)) => (location, pointer.to_owned()),
+            None => (reference, String::new()),
+        };
+
+        if location.is_empty() {
+            Reference::Relative(ReferenceRelative::parse(pointer))
+        } else if location.contains("://") {
+            Reference::Remote {
+                url: location.to_owned(),
+                pointer,
+            }
+        } else {
+            Reference::File {
+                path: location.to_owned(),
+                pointer,
+            }
+        }
+    }
+}
+
+impl ReferenceRelative {
+    fn parse(pointer: String) -> Self {
+        let segments: Vec<&str> = pointer.split('/').collect();
+
+        let name = match segments.as_slice() {
+            ["", "components", _, name] => name.to_string(),
+            _ => return ReferenceRelative::Pointer { pointer },
+        };
+
+        match segments[2] {
+            "responses" => ReferenceRelative::Response { name },
+            "parameters" => ReferenceRelative::Parameter { name },
+            "requestBodies" => ReferenceRelative::RequestBody { name },
+            "schemas" => ReferenceRelative::Schema { name },
+            "headers" => ReferenceRelative::Header { name },
+            _ => ReferenceRelative::Pointer { pointer },
+        }
+    }
+
+    /// JSON pointer to the referenced place
+    pub fn pointer(&self) -> String {
+        match self {
+            ReferenceRelative::Response { name } => format!("/components/responses/{}", name),
+            ReferenceRelative::Parameter { name } => format!("/components/parameters/{}", name),
+            ReferenceRelative::RequestBody { name } => {
+                format!("/components/requestBodies/{}", name)
+            }
+            ReferenceRelative::Schema { name } => format!("/components/schemas/{}", name),
+            ReferenceRelative::Header { name } => format!("/components/headers/{}", name),
+            ReferenceRelative::Responses { name } => format!("/components/responses/{}", name),
+            ReferenceRelative::Pointer { pointer } => pointer.clone(),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum ParseSchemaError {
     UnsupportedType,
     ReferenceNotSupported,
+
+    /// Referenced document cannot be loaded or has no such schema
+    UnresolvedReference(String),
+
+    /// References point to each other and never reach the schema
+    CircularReference(String),
 }
 
-fn parse_ref_or_schema(
-    name: &String,
-    ref_or: &ReferenceOr<openapiv3::Schema>,
-) -> Result<(FieldType, Vec<Component>), ParseSchemaError> {
-    match ref_or {
-        ReferenceOr::Item(schema) => parse_schema(&name, &schema),
-        ReferenceOr::Reference { reference } => {
-            log::info!(
-                "reference for schemas is not supported yet. Skipping {} for {}...",
-                reference,
-                name
-            );
+impl std::fmt::Display for ParseSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedType => write!(f, "schema type is not supported"),
+            Self::ReferenceNotSupported => write!(f, "reference is not supported"),
+            Self::UnresolvedReference(reason) => write!(f, "unresolved reference {}", reason),
+            Self::CircularReference(location) => write!(f, "circular reference at {}", location),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseSecuritySchemeError {
+    UnsupportedScheme,
+    ReferenceNotSupported,
+}
 
-            Err(ParseSchemaError::ReferenceNotSupported)
+impl std::fmt::Display for ParseSecuritySchemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedScheme => write!(f, "security scheme is not supported"),
+            Self::ReferenceNotSupported => write!(f, "reference is not supported"),
         }
     }
 }
 
-fn parse_schema(
-    name: &String,
-    schema: &openapiv3::Schema,
-) -> Result<(FieldType, Vec<Component>), ParseSchemaError> {
-    use inflections::Inflect;
+impl Components {
+    fn parse_ref_or_schema(
+        &mut self,
+        document: &Document,
+        name: &String,
+        ref_or: &ReferenceOr<openapiv3::Schema>,
+    ) -> Result<(FieldType, Vec<Component>), ParseSchemaError> {
+        match ref_or {
+            ReferenceOr::Item(schema) => self.parse_schema_kind(document, name, schema),
+            ReferenceOr::Reference { reference } => {
+                let reference = Reference::parse(reference);
 
-    let mut list = vec![];
+                // Schemas of the specification itself are parsed on their own
+                if let (Document::Root, Reference::Relative(ReferenceRelative::Schema { name })) =
+                    (document, &reference)
+                {
+                    return Ok((FieldType::Type(self.names.declared(name)), vec![]));
+                }
 
-    use openapiv3::{SchemaKind, Type};
+                let location = self.documents.locate(document, &reference);
+                let type_name = self.register(location, None);
 
-    match &schema.schema_kind {
-        SchemaKind::Type(schema_type) => {
-            let field_type = match schema_type {
-                Type::Number(_number) => FieldType::Number,
-                Type::Integer(_integer) => FieldType::Integer,
-                // TODO: parse enum
-                Type::String(_string) => FieldType::String,
-                Type::Boolean {} => FieldType::Boolean,
-                Type::Object(object) => {
-                    let (fields, mut created_components) = parse_schema_object(&name, object)?;
+                Ok((FieldType::Type(type_name), vec![]))
+            }
+        }
+    }
 
-                    let component_name = name.clone().to_pascal_case();
-                    let component = Component {
-                        name: component_name.clone(),
-                        description: schema.schema_data.description.clone(),
-                        kind: ComponentKind::Object { fields },
+    /// Schema declared right inside of other schema, name of the type is synthesized from the parent
+    fn parse_inline_schema(
+        &mut self,
+        document: &Document,
+        name: &String,
+        ref_or: &ReferenceOr<openapiv3::Schema>,
+    ) -> Result<(FieldType, Vec<Component>), ParseSchemaError> {
+        use openapiv3::{SchemaKind, Type};
+
+        match ref_or {
+            ReferenceOr::Item(schema) => match &schema.schema_kind {
+                SchemaKind::Type(Type::Object(_)) => {
+                    let type_name = self.names.inline(name, &schema.schema_data);
+
+                    self.parse_schema_kind(document, &type_name, schema)
+                }
+                SchemaKind::Type(schema_type)
+                    if !enum_values(&schema.schema_data, schema_type).is_empty() =>
+                {
+                    let type_name = self.names.inline(name, &schema.schema_data);
+
+                    self.parse_schema_kind(document, &type_name, schema)
+                }
+                SchemaKind::Any(any) if !any.properties.is_empty() => {
+                    let type_name = self.names.inline(name, &schema.schema_data);
+
+                    self.parse_schema_kind(document, &type_name, schema)
+                }
+                _ => self.parse_schema_kind(document, name, schema),
+            },
+            reference => self.parse_ref_or_schema(document, name, reference),
+        }
+    }
+
+    /// Parse schema, `name` is the name of the type if schema is an object
+    fn parse_schema_kind(
+        &mut self,
+        document: &Document,
+        name: &String,
+        schema: &openapiv3::Schema,
+    ) -> Result<(FieldType, Vec<Component>), ParseSchemaError> {
+        let mut list = vec![];
+
+        use openapiv3::{SchemaKind, Type};
+
+        match &schema.schema_kind {
+            SchemaKind::Type(schema_type) => {
+                let values = enum_values(&schema.schema_data, schema_type);
+
+                if !values.is_empty() {
+                    let kind = match values.len() {
+                        1 => ComponentKind::Const {
+                            value: values[0].clone(),
+                        },
+                        _ => ComponentKind::Enum {
+                            variants: enum_variants(&schema.schema_data, values),
+                        },
                     };
 
-                    list.push(component);
-                    list.append(&mut created_components);
+                    list.push(Component {
+                        name: name.clone(),
+                        description: schema.schema_data.description.clone(),
+                        kind,
+                    });
 
-                    FieldType::Type(component_name.clone())
+                    return Ok((FieldType::Type(name.clone()), list));
                 }
-                Type::Array(_array) => unimplemented!(),
+
+                let field_type = match schema_type {
+                    Type::Number(_number) => FieldType::Number,
+                    Type::Integer(_integer) => FieldType::Integer,
+                    Type::String(_string) => FieldType::String,
+                    Type::Boolean {} => FieldType::Boolean,
+                    Type::Object(object) => {
+                        let (fields, mut created_components) =
+                            self.parse_schema_object(document, name, object)?;
+
+                        let component = Component {
+                            name: name.clone(),
+                            description: schema.schema_data.description.clone(),
+                            kind: ComponentKind::Object { fields },
+                        };
+
+                        list.push(component);
+                        list.append(&mut created_components);
+
+                        FieldType::Type(name.clone())
+                    }
+                    Type::Array(array) => {
+                        let items = match &array.items {
+                            Some(items) => items.clone().unbox(),
+                            None => return Err(ParseSchemaError::UnsupportedType),
+                        };
+                        let item_name = format!("{}Item", name);
+                        let (item_type, mut created_components) =
+                            self.parse_inline_schema(document, &item_name, &items)?;
+
+                        list.append(&mut created_components);
+
+                        FieldType::Array(Box::new(item_type))
+                    }
+                };
+
+                Ok((field_type, list))
+            }
+            // Object without `type`, only its `properties` are declared
+            SchemaKind::Any(any) if !any.properties.is_empty() => {
+                let object = openapiv3::ObjectType {
+                    properties: any.properties.clone(),
+                    required: any.required.clone(),
+                    additional_properties: any.additional_properties.clone(),
+                    min_properties: any.min_properties,
+                    max_properties: any.max_properties,
+                };
+                let schema = Schema {
+                    schema_data: schema.schema_data.clone(),
+                    schema_kind: SchemaKind::Type(Type::Object(object)),
+                };
+
+                self.parse_schema_kind(document, name, &schema)
+            }
+            other => {
+                log::info!("this schema kind is not supported {:?}", other);
+                Err(ParseSchemaError::UnsupportedType)
+            }
+        }
+    }
+
+    fn parse_schema_object(
+        &mut self,
+        document: &Document,
+        name: &String,
+        schema_object: &openapiv3::ObjectType,
+    ) -> Result<(indexmap::IndexMap<String, ComponentField>, Vec<Component>), ParseSchemaError>
+    {
+        use inflections::Inflect;
+
+        let mut components = vec![];
+        let mut fields = indexmap::IndexMap::new();
+
+        for (field_name, schema) in schema_object.properties.iter() {
+            let inner_name = format!("{}{}", name, field_name.clone().to_pascal_case());
+
+            let (field_type, mut created_components) =
+                self.parse_inline_schema(document, &inner_name, &schema.clone().unbox())?;
+
+            components.append(&mut created_components);
+
+            let (default, read_only, write_only) = match schema {
+                ReferenceOr::Item(schema) => (
+                    schema.schema_data.default.as_ref().and_then(parse_default),
+                    schema.schema_data.read_only,
+                    schema.schema_data.write_only,
+                ),
+                ReferenceOr::Reference { .. } => (None, false, false),
+            };
+
+            let field = ComponentField {
+                required: schema_object
+                    .required
+                    .iter()
+                    .find(|found| *found == field_name)
+                    .is_some(),
+                description: None, // TODO: parse field description
+                field_type,
+                default,
+                read_only,
+                write_only,
+                skip_serializing: false,
             };
 
-            Ok((field_type, list))
+            fields.insert(field_name.clone(), field);
         }
+
+        Ok((fields, components))
+    }
+}
+
+/// Component for schema declared under `name` that has no type of its own, `type: string` for example
+///
+/// `None` when the schema is generated as type with this name, objects and enums are
+fn named_component(
+    name: &str,
+    description: Option<String>,
+    field_type: FieldType,
+) -> Option<Component> {
+    let kind = match field_type {
+        FieldType::Type(type_name) if type_name == name => return None,
+        FieldType::String => ComponentKind::String,
+        FieldType::Integer => ComponentKind::Integer,
+        FieldType::Number => ComponentKind::Number,
+        FieldType::Boolean => ComponentKind::Boolean,
+        FieldType::Array(items) => ComponentKind::Array { items: *items },
+        target => ComponentKind::Alias { target },
+    };
+
+    Some(Component {
+        name: name.to_owned(),
+        description,
+        kind,
+    })
+}
+
+/// Values of `enum`, `null` is skipped
+fn enum_values(
+    schema_data: &openapiv3::SchemaData,
+    schema_type: &openapiv3::Type,
+) -> Vec<EnumValue> {
+    use openapiv3::Type;
+
+    match schema_type {
+        Type::String(string) => string
+            .enumeration
+            .iter()
+            .flatten()
+            .map(|value| EnumValue::String(value.clone()))
+            .collect(),
+        Type::Integer(integer) => integer
+            .enumeration
+            .iter()
+            .flatten()
+            .map(|value| EnumValue::Integer(*value))
+            .collect(),
+        Type::Number(number) => number
+            .enumeration
+            .iter()
+            .flatten()
+            .map(|value| EnumValue::Number(*value))
+            .collect(),
+        Type::Boolean {} => schema_data
+            .extensions
+            .get(documents::CONST_EXTENSION)
+            .and_then(|value| value.as_bool())
+            .map(EnumValue::Boolean)
+            .into_iter()
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Value from `default`, lists are supported with scalar items, objects only when empty
+fn parse_default(value: &serde_json::Value) -> Option<DefaultValue> {
+    use serde_json::Value;
+
+    match value {
+        Value::Array(list) if list.is_empty() => Some(DefaultValue::Empty),
+        Value::Object(object) if object.is_empty() => Some(DefaultValue::Empty),
+        Value::Array(list) => match list.iter().map(parse_scalar_default).collect() {
+            Some(items) => Some(DefaultValue::List(items)),
+            None => {
+                log::warn!("default value is not supported {}", value);
+                None
+            }
+        },
         other => {
-            log::info!("this schema kind is not supported {:?}", other);
-            Err(ParseSchemaError::UnsupportedType)
+            let parsed = parse_scalar_default(other);
+
+            if parsed.is_none() {
+                log::warn!("default value is not supported {}", other);
+            }
+
+            parsed
         }
     }
 }
 
-fn parse_schema_object(
-    name: &String,
-    schema_object: &openapiv3::ObjectType,
-) -> Result<(indexmap::IndexMap<String, ComponentField>, Vec<Component>), ParseSchemaError> {
-    use inflections::Inflect;
+/// String, number or boolean from `default`
+fn parse_scalar_default(value: &serde_json::Value) -> Option<DefaultValue> {
+    use serde_json::Value;
 
-    let mut components = vec![];
-    let mut fields = indexmap::IndexMap::new();
+    match value {
+        Value::String(value) => Some(DefaultValue::Value(EnumValue::String(value.clone()))),
+        Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+            (Some(value), _) => Some(DefaultValue::Value(EnumValue::Integer(value))),
+            (None, Some(value)) => Some(DefaultValue::Value(EnumValue::Number(value))),
+            _ => None,
+        },
+        Value::Bool(value) => Some(DefaultValue::Boolean(*value)),
+        _ => None,
+    }
+}
 
-    for (field_name, schema) in schema_object.properties.iter() {
-        let inner_name = format!("{}{}", name, field_name.clone().to_pascal_case());
+/// Values with names from `x-enum-varnames`, names are matched by position
+fn enum_variants(data: &openapiv3::SchemaData, values: Vec<EnumValue>) -> Vec<EnumVariant> {
+    let names: Vec<_> = data
+        .extensions
+        .get(ENUM_VARNAMES_EXTENSION)
+        .and_then(|names| names.as_array())
+        .map(|names| {
+            names
+                .iter()
+                .map(|name| name.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
 
-        let (field_type, mut created_components) =
-            parse_ref_or_schema(&inner_name, &schema.clone().unbox())?;
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| EnumVariant {
+            name: names.get(index).cloned().flatten(),
+            value,
+        })
+        .collect()
+}
+
+impl EnumVariant {
+    /// Name from `x-enum-varnames` or the value itself
+    fn type_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => self.value.to_string().replace('.', " point "),
+        }
+    }
+}
+
+impl EnumValue {
+    /// Integer default of the number field is the same value
+    fn matches(&self, other: &EnumValue) -> bool {
+        match (self, other) {
+            (EnumValue::Number(number), EnumValue::Integer(integer))
+            | (EnumValue::Integer(integer), EnumValue::Number(number)) => {
+                *number == *integer as f64
+            }
+            (left, right) => left == right,
+        }
+    }
+}
+
+impl Components {
+    /// Defaults of the fields are converted to the field types,
+    /// values that cannot be converted are dropped and field stays optional
+    fn resolve_defaults(&mut self) {
+        let kinds: IndexMap<String, ComponentKind> = self
+            .schemas
+            .iter()
+            .map(|(name, component)| (name.clone(), component.kind.clone()))
+            .collect();
 
-        components.append(&mut created_components);
+        for component in self.schemas.values_mut() {
+            if let ComponentKind::Object { fields } = &mut component.kind {
+                for (field_name, field) in fields.iter_mut() {
+                    if let Some(default) = field.default.take() {
+                        field.default = resolve_default(&kinds, default.clone(), &field.field_type);
 
-        let field = ComponentField {
-            required: schema_object
-                .required
+                        if field.default.is_none() {
+                            log::info!(
+                                "default {:?} does not fit field {} of {}",
+                                default,
+                                field_name,
+                                component.name
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // Dropped defaults make objects required, so `{}` of them is dropped until nothing changes
+        loop {
+            let kinds: IndexMap<String, ComponentKind> = self
+                .schemas
                 .iter()
-                .find(|found| *found == field_name)
-                .is_some(),
-            description: None, // TODO: parse field description
-            field_type,
-        };
+                .map(|(name, component)| (name.clone(), component.kind.clone()))
+                .collect();
+            let mut changed = false;
+
+            for component in self.schemas.values_mut() {
+                if let ComponentKind::Object { fields } = &mut component.kind {
+                    for (field_name, field) in fields.iter_mut() {
+                        let dropped = field.default == Some(DefaultValue::Empty)
+                            && object_fields(&kinds, &field.field_type)
+                                .is_some_and(|fields| !defaultable(fields));
 
-        fields.insert(field_name.clone(), field);
+                        if dropped {
+                            log::info!(
+                                "default {{}} does not fit field {} of {}, some fields are required",
+                                field_name,
+                                component.name
+                            );
+                            field.default = None;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
     }
 
-    Ok((fields, components))
+    /// Put fields on the heap where type contains itself through direct fields,
+    /// otherwise recursive struct has infinite size
+    ///
+    /// Arrays are never boxed, `Vec` already has a known size
+    fn box_recursive_fields(&mut self) {
+        let edges: IndexMap<String, Vec<String>> = self
+            .schemas
+            .iter()
+            .map(|(name, component)| {
+                let targets = match &component.kind {
+                    ComponentKind::Object { fields } => fields
+                        .values()
+                        .filter_map(|field| match &field.field_type {
+                            FieldType::Type(target) => Some(target.clone()),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
+
+                (name.clone(), targets)
+            })
+            .collect();
+
+        for (name, component) in self.schemas.iter_mut() {
+            if let ComponentKind::Object { fields } = &mut component.kind {
+                for field in fields.values_mut() {
+                    let recursive = match &field.field_type {
+                        FieldType::Type(target) => reaches(&edges, target, name),
+                        _ => false,
+                    };
+
+                    if recursive {
+                        field.field_type = FieldType::Boxed(Box::new(field.field_type.clone()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn resolve_default(
+    kinds: &IndexMap<String, ComponentKind>,
+    default: DefaultValue,
+    field_type: &FieldType,
+) -> Option<DefaultValue> {
+    match (field_type, default) {
+        (FieldType::Boxed(inner), default) => resolve_default(kinds, default, inner),
+        (FieldType::String, default @ DefaultValue::Value(EnumValue::String(_)))
+        | (FieldType::Integer, default @ DefaultValue::Value(EnumValue::Integer(_)))
+        | (FieldType::Number, default @ DefaultValue::Value(EnumValue::Number(_)))
+        | (FieldType::Boolean, default @ DefaultValue::Boolean(_))
+        | (FieldType::Array(_), default @ DefaultValue::Empty) => Some(default),
+        (FieldType::Number, DefaultValue::Value(EnumValue::Integer(value))) => {
+            Some(DefaultValue::Value(EnumValue::Number(value as f64)))
+        }
+        (FieldType::Array(inner), DefaultValue::List(items)) => items
+            .into_iter()
+            .map(|item| resolve_default(kinds, item, inner))
+            .collect::<Option<_>>()
+            .map(DefaultValue::List),
+        (FieldType::Type(name), default) => match (kinds.get(name)?, default) {
+            (ComponentKind::Enum { variants }, DefaultValue::Value(value)) => variants
+                .iter()
+                .find(|variant| variant.value.matches(&value))
+                .map(|variant| DefaultValue::Variant(variant.type_name())),
+            (ComponentKind::Const { value }, DefaultValue::Value(default)) => {
+                match value.matches(&default) {
+                    true => Some(DefaultValue::Empty),
+                    false => None,
+                }
+            }
+            (ComponentKind::Const { value }, DefaultValue::Boolean(default)) => {
+                match *value == EnumValue::Boolean(default) {
+                    true => Some(DefaultValue::Empty),
+                    false => None,
+                }
+            }
+            (ComponentKind::Object { fields }, default @ DefaultValue::Empty) => {
+                match defaultable(fields) {
+                    true => Some(default),
+                    false => None,
+                }
+            }
+            (ComponentKind::Array { .. }, default @ DefaultValue::Empty) => Some(default),
+            (ComponentKind::String, default) => resolve_default(kinds, default, &FieldType::String),
+            (ComponentKind::Integer, default) => {
+                resolve_default(kinds, default, &FieldType::Integer)
+            }
+            (ComponentKind::Number, default) => resolve_default(kinds, default, &FieldType::Number),
+            (ComponentKind::Boolean, default) => {
+                resolve_default(kinds, default, &FieldType::Boolean)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
-impl Into<printer::GeneratedModule> for Components {
-    fn into(self) -> printer::GeneratedModule {
+/// View of the object with fields kept by `keep`, types of the fields refer to views in `names`
+fn view<F>(
+    component: &Component,
+    suffix: &str,
+    left_out: &str,
+    names: &IndexMap<String, String>,
+    keep: F,
+) -> Component
+where
+    F: Fn(&ComponentField) -> bool,
+{
+    let fields = match &component.kind {
+        ComponentKind::Object { fields } => fields
+            .iter()
+            .filter(|(_, field)| keep(field))
+            .map(|(name, field)| {
+                let field = ComponentField {
+                    field_type: field.field_type.renamed(names),
+                    read_only: false,
+                    write_only: false,
+                    ..field.clone()
+                };
+
+                (name.clone(), field)
+            })
+            .collect(),
+        _ => IndexMap::new(),
+    };
+
+    Component {
+        description: Some(format!(
+            "`{}` in {}s, {} properties are left out",
+            component.name,
+            suffix.to_lowercase(),
+            left_out
+        )),
+        name: names[&component.name].clone(),
+        kind: ComponentKind::Object { fields },
+    }
+}
+
+/// Field type refers to some of the `names`
+fn refers_to_any(field_type: &FieldType, names: &[String]) -> bool {
+    match field_type {
+        FieldType::Type(name) => names.contains(name),
+        FieldType::Array(inner) | FieldType::Boxed(inner) => refers_to_any(inner, names),
+        _ => false,
+    }
+}
+
+/// Object can be created empty when every field is optional or has default, `{}` fits it
+fn defaultable(fields: &IndexMap<String, ComponentField>) -> bool {
+    fields
+        .values()
+        .all(|field| !field.required || field.default.is_some())
+}
+
+/// Fields of the object the field type refers to
+fn object_fields<'a>(
+    kinds: &'a IndexMap<String, ComponentKind>,
+    field_type: &FieldType,
+) -> Option<&'a IndexMap<String, ComponentField>> {
+    match field_type {
+        FieldType::Boxed(inner) => object_fields(kinds, inner),
+        FieldType::Type(name) => match kinds.get(name)? {
+            ComponentKind::Object { fields } => Some(fields),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Type `from` contains type `to` through direct fields
+fn reaches(edges: &IndexMap<String, Vec<String>>, from: &str, to: &str) -> bool {
+    let mut visited = std::collections::HashSet::new();
+    let mut queue = vec![from];
+
+    while let Some(current) = queue.pop() {
+        if current == to {
+            return true;
+        }
+
+        if visited.insert(current) {
+            if let Some(targets) = edges.get(current) {
+                queue.extend(targets.iter().map(String::as_str));
+            }
+        }
+    }
+
+    false
+}
+
+impl From<Components> for printer::GeneratedModule {
+    fn from(mut components: Components) -> Self {
+        components.box_recursive_fields();
+        components.resolve_defaults();
+
         let mut module = printer::GeneratedModule::default();
 
-        for (_, component) in self.schemas.into_iter() {
+        for (_, component) in components.parameters.into_iter() {
+            module.components.parameters.list.push(component.into());
+        }
+
+        for (_, component) in components.request_bodies.into_iter() {
+            module.components.request_bodies.list.push(component.into());
+        }
+
+        for (_, component) in components.responses.into_iter() {
+            module.components.responses.list.push(component.into());
+        }
+
+        for (_, component) in components.schemas.into_iter() {
             module.components.schemas.list.push(component.into());
         }
 
+        let mut client = printer::client::ClientModule::default();
+
+        for operation in components.operations.iter() {
+            module
+                .api
+                .structure
+                .operations
+                .push((operation.path.clone(), operation.method.clone()));
+            module.api.methods.methods.push(operation.into());
+            module.paths.paths.push(operation.into());
+            client.methods.push(operation.into());
+        }
+
+        module.client = Some(client);
+
+        for (_, scheme) in components.security_schemes.into_iter() {
+            let scheme: comp::security_schemes::SecurityScheme = scheme.into();
+
+            module.api.methods.security_schemes.push(scheme.clone());
+            module.components.security_schemes.list.push(scheme);
+        }
+
         module
     }
 }
 
 use printer::components as comp;
 
-impl Into<comp::Component> for Component {
-    fn into(self) -> comp::Component {
-        match self.kind {
+impl From<Component> for comp::Component {
+    fn from(component: Component) -> Self {
+        match component.kind {
             ComponentKind::Object { fields } => {
                 let mut target_fields = vec![];
 
@@ -335,47 +1271,168 @@ impl Into<comp::Component> for Component {
                     target_fields.push(into_field_type(&field_name, field));
                 }
 
-                let component = comp::Component::Object {
-                    name: self.name,
-                    description: self.description,
+                comp::Component::Object {
+                    name: component.name,
+                    description: component.description,
                     fields: target_fields,
-                };
-
-                component
+                }
             }
             ComponentKind::String => comp::Component::Type {
-                name: self.name,
-                description: self.description,
+                name: component.name,
+                description: component.description,
                 type_value: comp::FieldType::Native(comp::NativeType::String {
                     format: Default::default(),
                 }),
             },
             ComponentKind::Integer => comp::Component::Type {
-                name: self.name,
-                description: self.description,
+                name: component.name,
+                description: component.description,
                 type_value: comp::FieldType::Native(comp::NativeType::Integer {
                     format: Default::default(),
                 }),
             },
             ComponentKind::Number => comp::Component::Type {
-                name: self.name,
-                description: self.description,
+                name: component.name,
+                description: component.description,
                 type_value: comp::FieldType::Native(comp::NativeType::Float {
                     format: Default::default(),
                 }),
             },
             ComponentKind::Boolean => comp::Component::Type {
-                name: self.name,
-                description: self.description,
+                name: component.name,
+                description: component.description,
                 type_value: comp::FieldType::Native(comp::NativeType::Boolean),
             },
-            _ => unimplemented!(),
+            ComponentKind::Enum { variants } => {
+                let is_string = variants
+                    .iter()
+                    .all(|variant| matches!(variant.value, EnumValue::String(_)));
+
+                match is_string {
+                    true => comp::Component::Enum {
+                        name: component.name,
+                        description: component.description,
+                        variants: variants
+                            .into_iter()
+                            .map(|variant| comp::EnumVariant {
+                                name: variant.value.to_string(),
+                                description: None,
+                                x_variant_name: variant.name,
+                            })
+                            .collect(),
+                    },
+                    false => comp::Component::ValueEnum {
+                        name: component.name,
+                        description: component.description,
+                        variants: variants
+                            .into_iter()
+                            .map(|variant| comp::ValueVariant {
+                                name: variant.type_name(),
+                                description: None,
+                                value: variant.value.into(),
+                            })
+                            .collect(),
+                    },
+                }
+            }
+            ComponentKind::Const { value } => comp::Component::Const {
+                name: component.name,
+                description: component.description,
+                value: value.into(),
+            },
+            ComponentKind::Array { items } => comp::Component::Type {
+                name: component.name,
+                description: component.description,
+                type_value: into_printer_type(FieldType::Array(Box::new(items))),
+            },
+            ComponentKind::Alias { target } => comp::Component::Type {
+                name: component.name,
+                description: component.description,
+                type_value: into_printer_type(target),
+            },
+        }
+    }
+}
+
+impl From<EnumValue> for comp::EnumValue {
+    fn from(value: EnumValue) -> Self {
+        match value {
+            EnumValue::String(value) => comp::EnumValue::String(value),
+            EnumValue::Integer(value) => comp::EnumValue::Integer(value),
+            EnumValue::Number(value) => comp::EnumValue::Number(value),
+            EnumValue::Boolean(value) => comp::EnumValue::Boolean(value),
+        }
+    }
+}
+
+impl From<DefaultValue> for comp::DefaultValue {
+    fn from(default: DefaultValue) -> Self {
+        match default {
+            DefaultValue::Value(EnumValue::String(value)) => comp::DefaultValue::String(value),
+            DefaultValue::Value(EnumValue::Integer(value)) => comp::DefaultValue::Integer(value),
+            DefaultValue::Value(EnumValue::Number(value)) => comp::DefaultValue::Number(value),
+            DefaultValue::Value(EnumValue::Boolean(value)) | DefaultValue::Boolean(value) => {
+                comp::DefaultValue::Boolean(value)
+            }
+            DefaultValue::Variant(name) => comp::DefaultValue::Variant(name),
+            DefaultValue::List(items) => {
+                comp::DefaultValue::List(items.into_iter().map(Into::into).collect())
+            }
+            DefaultValue::Empty => comp::DefaultValue::Empty,
         }
     }
 }
 
-fn into_field_type(name: &String, field: ComponentField) -> comp::Field {
-    let field_type = match field.field_type {
+impl std::fmt::Display for EnumValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnumValue::String(value) => write!(f, "{}", value),
+            EnumValue::Integer(value) => write!(f, "{}", value),
+            EnumValue::Number(value) => write!(f, "{}", value),
+            EnumValue::Boolean(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<SecurityScheme> for comp::security_schemes::SecurityScheme {
+    fn from(scheme: SecurityScheme) -> Self {
+        use comp::security_schemes as target;
+
+        let kind = match scheme.kind {
+            SecuritySchemeKind::Bearer => target::SecuritySchemeKind::Bearer,
+            SecuritySchemeKind::Basic => target::SecuritySchemeKind::Basic,
+            SecuritySchemeKind::ApiKey { location, name } => {
+                let location = match location {
+                    ApiKeyLocation::Header => target::ApiKeyLocation::Header,
+                    ApiKeyLocation::Query => target::ApiKeyLocation::Query,
+                    ApiKeyLocation::Cookie => target::ApiKeyLocation::Cookie,
+                };
+
+                target::SecuritySchemeKind::ApiKey { location, name }
+            }
+        };
+
+        target::SecurityScheme {
+            name: scheme.name,
+            description: scheme.description,
+            kind,
+        }
+    }
+}
+
+fn into_field_type(name: &str, field: ComponentField) -> comp::Field {
+    comp::Field {
+        name: name.to_owned(),
+        description: field.description,
+        required: field.required,
+        field_type: into_printer_type(field.field_type),
+        default: field.default.map(Into::into),
+        skip_serializing: field.skip_serializing,
+    }
+}
+
+fn into_printer_type(field_type: FieldType) -> comp::FieldType {
+    match field_type {
         FieldType::String => comp::FieldType::Native(comp::NativeType::String {
             format: Default::default(),
         }),
@@ -393,12 +1450,51 @@ fn into_field_type(name: &String, field: ComponentField) -> comp::Field {
                 comp::FieldType::Custom(type_path)
             }
         }
-    };
+        FieldType::Array(items) => comp::FieldType::Array(Box::new(into_printer_type(*items))),
+        FieldType::Boxed(inner) => comp::FieldType::Boxed(Box::new(into_printer_type(*inner))),
+    }
+}
 
-    comp::Field {
-        name: name.clone(),
-        description: field.description,
-        required: field.required,
-        field_type,
+#[cfg(test)]
+mod tests {
+    use super::{parse_default, resolve_default, DefaultValue, EnumValue, FieldType};
+    use indexmap::IndexMap;
+    use serde_json::json;
+
+    #[test]
+    fn list_defaults_of_scalars() {
+        let strings = FieldType::Array(Box::new(FieldType::String));
+        let default = parse_default(&json!(["new", "hot"])).unwrap();
+
+        assert_eq!(
+            resolve_default(&IndexMap::new(), default, &strings),
+            Some(DefaultValue::List(vec![
+                DefaultValue::Value(EnumValue::String("new".to_owned())),
+                DefaultValue::Value(EnumValue::String("hot".to_owned())),
+            ]))
+        );
+
+        let numbers = FieldType::Array(Box::new(FieldType::Number));
+        let default = parse_default(&json!([1, 2.5])).unwrap();
+
+        assert_eq!(
+            resolve_default(&IndexMap::new(), default, &numbers),
+            Some(DefaultValue::List(vec![
+                DefaultValue::Value(EnumValue::Number(1.0)),
+                DefaultValue::Value(EnumValue::Number(2.5)),
+            ]))
+        );
+
+        let default = parse_default(&json!([1, "two"])).unwrap();
+        assert_eq!(resolve_default(&IndexMap::new(), default, &numbers), None);
+    }
+
+    #[test]
+    fn unsupported_defaults_fall_back_to_optional() {
+        assert_eq!(parse_default(&json!([["nested"]])), None);
+        assert_eq!(parse_default(&json!([{ "id": 1 }])), None);
+        assert_eq!(parse_default(&json!({ "id": 1 })), None);
+        assert_eq!(parse_default(&json!(null)), None);
+        assert_eq!(parse_default(&json!([])), Some(DefaultValue::Empty));
     }
 }
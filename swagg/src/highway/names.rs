@@ -0,0 +1,71 @@
+//! Names of the generated types, each name is given once
+
+use indexmap::IndexMap;
+use inflections::Inflect;
+use openapiv3::SchemaData;
+use std::collections::HashSet;
+
+/// Extension with name of the type, `x-rust-type-name: HttpRequest`
+const TYPE_NAME_EXTENSION: &str = "x-rust-type-name";
+
+#[derive(Debug, Default)]
+pub struct Names {
+    /// Type names of schemas from `components.schemas` of the specification
+    declared: IndexMap<String, String>,
+    taken: HashSet<String>,
+}
+
+impl Names {
+    /// Reserve name for schema from `components.schemas`, before any inline type is named
+    pub fn declare(&mut self, key: &str, data: Option<&SchemaData>) -> String {
+        let candidate = data
+            .and_then(type_name_extension)
+            .unwrap_or_else(|| key.to_pascal_case());
+        let name = self.unique(candidate);
+
+        self.declared.insert(key.to_owned(), name.clone());
+        name
+    }
+
+    /// Type name of schema from `components.schemas`
+    pub fn declared(&self, key: &str) -> String {
+        match self.declared.get(key) {
+            Some(name) => name.clone(),
+            None => key.to_pascal_case(),
+        }
+    }
+
+    /// Name of the type declared inside of other schema, `title` and extension are preferred
+    pub fn inline(&mut self, base: &str, data: &SchemaData) -> String {
+        let candidate = type_name_extension(data)
+            .or_else(|| data.title.as_ref().map(|title| title.to_pascal_case()))
+            .unwrap_or_else(|| base.to_pascal_case());
+
+        self.unique(candidate)
+    }
+
+    /// Candidate itself, or with the first free number suffix, `User2`, `User3`
+    pub fn unique(&mut self, candidate: String) -> String {
+        let mut name = candidate.clone();
+        let mut suffix = 1;
+
+        while self.taken.contains(&name) {
+            suffix += 1;
+            name = format!("{}{}", candidate, suffix);
+        }
+
+        if name != candidate {
+            log::info!("type name {} is taken, using {}", candidate, name);
+        }
+
+        self.taken.insert(name.clone());
+        name
+    }
+}
+
+fn type_name_extension(data: &SchemaData) -> Option<String> {
+    data.extensions
+        .get(TYPE_NAME_EXTENSION)
+        .and_then(|value| value.as_str())
+        .map(|name| name.to_pascal_case())
+}
@@ -0,0 +1,873 @@
+//! Operations of `paths`, each one gets module in `paths`, method of the client and binder of the api
+
+use super::{
+    into_printer_type, Component, ComponentKind, Components, Document, FieldType, ParseSchemaError,
+    Reference, ReferenceRelative,
+};
+use crate::printer::{self, api::HttpMethod, ident::to_pascal_ident, paths::ResponseStatus};
+use inflections::Inflect;
+use openapiv3::{
+    Header, MediaType, Parameter, ParameterSchemaOrContent, PathItem, ReferenceOr, RequestBody,
+    Response, Schema, SecurityRequirement, StatusCode,
+};
+
+/// Extension with name of the response variant, `x-variant-name: Done`
+const VARIANT_NAME_EXTENSION: &str = "x-variant-name";
+
+#[derive(Debug)]
+pub struct Operation {
+    /// `operationId`, or method with path when it is not set
+    pub name: String,
+    pub path: String,
+    pub method: HttpMethod,
+    pub description: Option<String>,
+
+    /// Name of the type in `components::request_bodies`
+    pub request_body: Option<String>,
+    pub params: Vec<Param>,
+    pub responses: Vec<OperationResponse>,
+    pub tags: Vec<String>,
+
+    /// Alternatives of security requirements, names of the schemes with scopes
+    pub security: Vec<Vec<(String, Vec<String>)>>,
+}
+
+#[derive(Debug)]
+pub struct Param {
+    pub name: String,
+    pub location: ParamLocation,
+
+    /// Name of the type in `components::parameters`
+    pub type_name: String,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamLocation {
+    Query,
+    Path,
+    Header,
+    Cookie,
+}
+
+#[derive(Debug)]
+pub struct OperationResponse {
+    pub status: u16,
+    pub description: Option<String>,
+
+    /// Name of the type in `components::responses`, response has no body without it
+    pub type_name: Option<String>,
+
+    /// Variant name from `x-variant-name`
+    pub variant_name: Option<String>,
+
+    pub headers: Vec<OperationHeader>,
+
+    /// Cookies from `Set-Cookie` header
+    pub cookies: Vec<OperationCookie>,
+}
+
+#[derive(Debug)]
+pub struct OperationHeader {
+    pub name: String,
+    pub description: Option<String>,
+    pub required: bool,
+
+    /// Native type or type in `components::responses` for other schemas
+    pub field_type: FieldType,
+}
+
+#[derive(Debug)]
+pub struct OperationCookie {
+    /// Name from `example` of the `Set-Cookie` header, `session` for `session=abc; Path=/`
+    pub name: String,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
+#[derive(Debug)]
+pub enum ParseOperationError {
+    Schema(ParseSchemaError),
+
+    /// Path item or component is referenced not from `#/components` of the specification
+    ReferenceNotSupported(String),
+
+    /// Parameter is described with `content` instead of `schema`
+    ParameterContent(String),
+
+    /// Every alternative of security requirement needs unknown or unsupported scheme
+    UnsupportedSecurity(Vec<String>),
+}
+
+impl std::fmt::Display for ParseOperationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Schema(reason) => write!(f, "{}", reason),
+            Self::ReferenceNotSupported(reference) => {
+                write!(f, "reference {} is not supported", reference)
+            }
+            Self::ParameterContent(name) => {
+                write!(f, "parameter {} has content instead of schema", name)
+            }
+            Self::UnsupportedSecurity(schemes) => {
+                write!(
+                    f,
+                    "security schemes {} are not supported",
+                    schemes.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl From<ParseSchemaError> for ParseOperationError {
+    fn from(error: ParseSchemaError) -> Self {
+        Self::Schema(error)
+    }
+}
+
+impl Components {
+    /// Parse every operation of the path, failed ones are skipped
+    ///
+    /// Operations without own `security` get root `security` of the specification
+    pub fn parse_path(
+        &mut self,
+        path: &str,
+        item: &ReferenceOr<PathItem>,
+        components: &openapiv3::Components,
+        security: &Option<Vec<SecurityRequirement>>,
+    ) -> Result<(), ParseOperationError> {
+        let item = match item {
+            ReferenceOr::Item(item) => item,
+            ReferenceOr::Reference { reference } => {
+                return Err(ParseOperationError::ReferenceNotSupported(
+                    reference.clone(),
+                ))
+            }
+        };
+
+        for (method, operation) in item.iter() {
+            let method = match method {
+                "delete" => HttpMethod::Delete,
+                "get" => HttpMethod::Get,
+                "head" => HttpMethod::Head,
+                "options" => HttpMethod::Options,
+                "patch" => HttpMethod::Patch,
+                "post" => HttpMethod::Post,
+                "put" => HttpMethod::Put,
+                "trace" => HttpMethod::Trace,
+                other => {
+                    log::warn!("method {} of {} is not supported, skipping", other, path);
+                    continue;
+                }
+            };
+            let name = operation
+                .operation_id
+                .clone()
+                .unwrap_or_else(|| format!("{} {}", method, path));
+
+            let parsed = self
+                .parse_operation(path, method, &name, item, operation, components)
+                .and_then(|mut parsed| {
+                    // Empty `security` of the operation removes root requirements
+                    if operation.security.is_none() {
+                        parsed.security = requirements(security.iter().flatten());
+                    }
+                    self.supported_security(parsed)
+                });
+
+            match parsed {
+                Ok(parsed) => self.operations.push(parsed),
+                Err(reason) => log::warn!("Failed operation {}: {}", name, reason),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drop alternatives of security requirement with schemes missing in `security_schemes`,
+    /// operation fails when no alternative is left as it could never be authorized
+    fn supported_security(
+        &self,
+        mut operation: Operation,
+    ) -> Result<Operation, ParseOperationError> {
+        let mut unsupported = vec![];
+
+        operation.security.retain(|alternative| {
+            let missing = alternative
+                .iter()
+                .map(|(scheme, _)| scheme)
+                .filter(|scheme| !self.security_schemes.contains_key(*scheme))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for scheme in missing.iter() {
+                if !unsupported.contains(scheme) {
+                    unsupported.push(scheme.clone());
+                }
+            }
+
+            missing.is_empty()
+        });
+
+        if operation.security.is_empty() && !unsupported.is_empty() {
+            return Err(ParseOperationError::UnsupportedSecurity(unsupported));
+        }
+
+        if !unsupported.is_empty() {
+            log::warn!(
+                "security schemes {} of {} are not supported, skipping alternatives",
+                unsupported.join(", "),
+                operation.name
+            );
+        }
+
+        Ok(operation)
+    }
+
+    fn parse_operation(
+        &mut self,
+        path: &str,
+        method: HttpMethod,
+        name: &str,
+        item: &PathItem,
+        operation: &openapiv3::Operation,
+        components: &openapiv3::Components,
+    ) -> Result<Operation, ParseOperationError> {
+        let mut params: Vec<Param> = vec![];
+
+        // Parameters of the operation override ones of the path with the same name and location
+        for parameter in operation.parameters.iter().chain(item.parameters.iter()) {
+            let param = self.parse_parameter(name, parameter, components)?;
+
+            let declared = params
+                .iter()
+                .any(|found| found.name == param.name && found.location == param.location);
+
+            if !declared {
+                params.push(param);
+            }
+        }
+
+        let request_body = match &operation.request_body {
+            Some(body) => self.parse_request_body(name, body, components)?,
+            None => None,
+        };
+
+        let mut responses = vec![];
+
+        for (status, response) in operation.responses.responses.iter() {
+            let code = match status {
+                StatusCode::Code(code) if ResponseStatus::from_code(*code).is_some() => *code,
+                other => {
+                    log::warn!("status {} of {} is not supported, skipping", other, name);
+                    continue;
+                }
+            };
+
+            responses.push(self.parse_response(name, code, response, components)?);
+        }
+
+        if operation.responses.default.is_some() {
+            log::info!("default response of {} is not supported, skipping", name);
+        }
+
+        Ok(Operation {
+            name: name.to_owned(),
+            path: path.to_owned(),
+            method,
+            description: operation
+                .description
+                .clone()
+                .or_else(|| operation.summary.clone()),
+            request_body,
+            params,
+            responses,
+            tags: operation.tags.clone(),
+            security: requirements(operation.security.iter().flatten()),
+        })
+    }
+
+    fn parse_parameter(
+        &mut self,
+        operation: &str,
+        parameter: &ReferenceOr<Parameter>,
+        components: &openapiv3::Components,
+    ) -> Result<Param, ParseOperationError> {
+        let (type_name, parameter) = match parameter {
+            ReferenceOr::Item(parameter) => {
+                let data = parameter.parameter_data_ref();
+                let type_name = format!(
+                    "{}{}",
+                    operation.to_pascal_case(),
+                    data.name.to_pascal_case()
+                );
+
+                (type_name, parameter)
+            }
+            ReferenceOr::Reference { reference } => match Reference::parse(reference) {
+                Reference::Relative(ReferenceRelative::Parameter { name }) => {
+                    match components.parameters.get(&name) {
+                        Some(ReferenceOr::Item(parameter)) => (name.to_pascal_case(), parameter),
+                        _ => {
+                            return Err(ParseOperationError::ReferenceNotSupported(
+                                reference.clone(),
+                            ))
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ParseOperationError::ReferenceNotSupported(
+                        reference.clone(),
+                    ))
+                }
+            },
+        };
+
+        let location = match parameter {
+            Parameter::Query { .. } => ParamLocation::Query,
+            Parameter::Path { .. } => ParamLocation::Path,
+            Parameter::Header { .. } => ParamLocation::Header,
+            Parameter::Cookie { .. } => ParamLocation::Cookie,
+        };
+        let data = parameter.parameter_data_ref();
+        let schema = match &data.format {
+            ParameterSchemaOrContent::Schema(schema) => schema,
+            ParameterSchemaOrContent::Content(_) => {
+                return Err(ParseOperationError::ParameterContent(data.name.clone()))
+            }
+        };
+
+        if !self.parameters.contains_key(&type_name) {
+            let component = self.alias(&type_name, data.description.clone(), schema)?;
+            self.parameters.insert(type_name.clone(), component);
+        }
+
+        Ok(Param {
+            name: data.name.clone(),
+            location,
+            type_name,
+            description: data.description.clone(),
+            required: data.required,
+        })
+    }
+
+    /// Name of the type for JSON body, `None` when body has other content type
+    fn parse_request_body(
+        &mut self,
+        operation: &str,
+        body: &ReferenceOr<RequestBody>,
+        components: &openapiv3::Components,
+    ) -> Result<Option<String>, ParseOperationError> {
+        let (type_name, body) = match body {
+            ReferenceOr::Item(body) => (format!("{}RequestBody", operation.to_pascal_case()), body),
+            ReferenceOr::Reference { reference } => match Reference::parse(reference) {
+                Reference::Relative(ReferenceRelative::RequestBody { name }) => {
+                    match components.request_bodies.get(&name) {
+                        Some(ReferenceOr::Item(body)) => (name.to_pascal_case(), body),
+                        _ => {
+                            return Err(ParseOperationError::ReferenceNotSupported(
+                                reference.clone(),
+                            ))
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ParseOperationError::ReferenceNotSupported(
+                        reference.clone(),
+                    ))
+                }
+            },
+        };
+
+        let schema = match json_schema(&body.content) {
+            Some(schema) => schema,
+            None => {
+                log::warn!("request body of {} is not JSON, skipping", operation);
+                return Ok(None);
+            }
+        };
+
+        if !self.request_bodies.contains_key(&type_name) {
+            let component = self.alias(&type_name, body.description.clone(), schema)?;
+            self.request_bodies.insert(type_name.clone(), component);
+        }
+
+        Ok(Some(type_name))
+    }
+
+    fn parse_response(
+        &mut self,
+        operation: &str,
+        status: u16,
+        response: &ReferenceOr<Response>,
+        components: &openapiv3::Components,
+    ) -> Result<OperationResponse, ParseOperationError> {
+        let (type_name, response) = match response {
+            ReferenceOr::Item(response) => {
+                let status_name = ResponseStatus::from_code(status)
+                    .map(|status| status.to_string())
+                    .unwrap_or_default();
+                let type_name = format!(
+                    "{}{}",
+                    operation.to_pascal_case(),
+                    status_name.to_pascal_case()
+                );
+
+                (type_name, response)
+            }
+            ReferenceOr::Reference { reference } => match Reference::parse(reference) {
+                Reference::Relative(ReferenceRelative::Response { name }) => {
+                    match components.responses.get(&name) {
+                        Some(ReferenceOr::Item(response)) => (name.to_pascal_case(), response),
+                        _ => {
+                            return Err(ParseOperationError::ReferenceNotSupported(
+                                reference.clone(),
+                            ))
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ParseOperationError::ReferenceNotSupported(
+                        reference.clone(),
+                    ))
+                }
+            },
+        };
+
+        let mut headers = vec![];
+        let mut cookies = vec![];
+
+        for (header_name, header) in response.headers.iter() {
+            let header = match header {
+                ReferenceOr::Item(header) => header,
+                ReferenceOr::Reference { reference } => match Reference::parse(reference) {
+                    Reference::Relative(ReferenceRelative::Header { name }) => {
+                        match components.headers.get(&name) {
+                            Some(ReferenceOr::Item(header)) => header,
+                            _ => {
+                                return Err(ParseOperationError::ReferenceNotSupported(
+                                    reference.clone(),
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(ParseOperationError::ReferenceNotSupported(
+                            reference.clone(),
+                        ))
+                    }
+                },
+            };
+
+            if !header_name.eq_ignore_ascii_case("set-cookie") {
+                headers.push(self.parse_response_header(&type_name, header_name, header)?);
+                continue;
+            }
+
+            match cookie_name(header) {
+                Some(name) => cookies.push(OperationCookie {
+                    name,
+                    description: header.description.clone(),
+                    required: header.required,
+                }),
+                None => log::warn!(
+                    "Set-Cookie of {} has no example with name of the cookie, skipping",
+                    operation
+                ),
+            }
+        }
+
+        let type_name = match json_schema(&response.content) {
+            Some(schema) => {
+                if !self.responses.contains_key(&type_name) {
+                    let description = Some(response.description.clone());
+                    let component = self.alias(&type_name, description, schema)?;
+                    self.responses.insert(type_name.clone(), component);
+                }
+
+                Some(type_name)
+            }
+            None if response.content.is_empty() => None,
+            None => {
+                log::warn!(
+                    "response {} of {} is not JSON, body is skipped",
+                    status,
+                    operation
+                );
+                None
+            }
+        };
+
+        Ok(OperationResponse {
+            status,
+            description: Some(response.description.clone()),
+            type_name,
+            variant_name: response
+                .extensions
+                .get(VARIANT_NAME_EXTENSION)
+                .and_then(|value| value.as_str())
+                .map(ToOwned::to_owned),
+            headers,
+            cookies,
+        })
+    }
+
+    /// Header of the response, `response` is the name of its types, `{Operation}{Status}`
+    ///
+    /// Header value is written with `Display`, so schemas other than strings, numbers and booleans
+    /// are read as strings
+    fn parse_response_header(
+        &mut self,
+        response: &str,
+        name: &str,
+        header: &Header,
+    ) -> Result<OperationHeader, ParseOperationError> {
+        let schema = match &header.format {
+            ParameterSchemaOrContent::Schema(schema) => schema,
+            ParameterSchemaOrContent::Content(_) => {
+                return Err(ParseOperationError::ParameterContent(name.to_owned()))
+            }
+        };
+        let type_name = format!("{}{}", response, name.to_pascal_case());
+
+        let (field_type, created_components) =
+            self.parse_inline_schema(&Document::Root, &type_name, schema)?;
+
+        for component in created_components.into_iter() {
+            self.insert_schema(component);
+        }
+
+        self.parse_pending()?;
+
+        let field_type = match field_type {
+            FieldType::Type(target) if self.is_plain(&target) => {
+                if !self.responses.contains_key(&type_name) {
+                    let component = Component {
+                        name: type_name.clone(),
+                        description: header.description.clone(),
+                        kind: ComponentKind::Alias {
+                            target: in_schemas(FieldType::Type(target)),
+                        },
+                    };
+                    self.responses.insert(type_name.clone(), component);
+                }
+
+                FieldType::Type(format!("responses::{}", to_pascal_ident(&type_name)))
+            }
+            FieldType::String | FieldType::Integer | FieldType::Number | FieldType::Boolean => {
+                field_type
+            }
+            FieldType::Array(items)
+                if matches!(
+                    *items,
+                    FieldType::String | FieldType::Integer | FieldType::Number | FieldType::Boolean
+                ) =>
+            {
+                FieldType::Array(items)
+            }
+            _ => {
+                log::warn!("header {} of {} is read as string", name, response);
+                FieldType::String
+            }
+        };
+
+        Ok(OperationHeader {
+            name: name.to_owned(),
+            description: header.description.clone(),
+            required: header.required,
+            field_type,
+        })
+    }
+
+    /// Schema is generated as string, number or boolean type
+    fn is_plain(&self, name: &str) -> bool {
+        match self.schemas.get(name).map(|component| &component.kind) {
+            Some(ComponentKind::String)
+            | Some(ComponentKind::Integer)
+            | Some(ComponentKind::Number)
+            | Some(ComponentKind::Boolean) => true,
+            Some(ComponentKind::Alias {
+                target: FieldType::Type(target),
+            }) => self.is_plain(target),
+            Some(ComponentKind::Alias { target }) => !matches!(target, FieldType::Array(_)),
+            _ => false,
+        }
+    }
+
+    /// Type in other module of `components` for the schema, types of the schema are generated in `schemas`
+    fn alias(
+        &mut self,
+        name: &str,
+        description: Option<String>,
+        schema: &ReferenceOr<Schema>,
+    ) -> Result<Component, ParseSchemaError> {
+        let (field_type, created_components) =
+            self.parse_inline_schema(&Document::Root, &name.to_owned(), schema)?;
+
+        for component in created_components.into_iter() {
+            self.insert_schema(component);
+        }
+
+        self.parse_pending()?;
+
+        Ok(Component {
+            name: name.to_owned(),
+            description,
+            kind: ComponentKind::Alias {
+                target: in_schemas(field_type),
+            },
+        })
+    }
+}
+
+/// Names of the schemes with scopes of every alternative
+fn requirements<'a, I>(security: I) -> Vec<Vec<(String, Vec<String>)>>
+where
+    I: Iterator<Item = &'a SecurityRequirement>,
+{
+    security
+        .map(|requirement| {
+            requirement
+                .iter()
+                .map(|(scheme, scopes)| (scheme.clone(), scopes.clone()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Name of the cookie from `example` of the `Set-Cookie` header or its schema
+fn cookie_name(header: &Header) -> Option<String> {
+    let schema_example = match &header.format {
+        ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+            schema.schema_data.example.as_ref()
+        }
+        _ => None,
+    };
+    let example = header.example.as_ref().or(schema_example)?.as_str()?;
+    let (name, _) = example.split_once('=')?;
+
+    match name.trim() {
+        "" => None,
+        name => Some(name.to_owned()),
+    }
+}
+
+/// Schema of JSON content, `application/json` or any `+json` type
+fn json_schema(content: &indexmap::IndexMap<String, MediaType>) -> Option<&ReferenceOr<Schema>> {
+    content
+        .iter()
+        .find(|(content_type, _)| {
+            let essence = content_type.split(';').next().unwrap_or_default().trim();
+
+            essence == "application/json" || essence.ends_with("+json")
+        })
+        .and_then(|(_, media)| media.schema.as_ref())
+}
+
+/// Type of the schema as seen from other module of `components`
+fn in_schemas(field_type: FieldType) -> FieldType {
+    match field_type {
+        FieldType::Type(name) => {
+            FieldType::Type(format!("super::schemas::{}", to_pascal_ident(&name)))
+        }
+        FieldType::Array(items) => FieldType::Array(Box::new(in_schemas(*items))),
+        FieldType::Boxed(inner) => FieldType::Boxed(Box::new(in_schemas(*inner))),
+        other => other,
+    }
+}
+
+impl From<&Operation> for printer::paths::Path {
+    fn from(operation: &Operation) -> Self {
+        use printer::paths::{Path, QueryParam, ResponseEnum};
+
+        Path {
+            name: operation.name.clone(),
+            response: ResponseEnum {
+                responses: operation.responses.iter().map(Into::into).collect(),
+            },
+            query_params: operation.params_in(ParamLocation::Query),
+            path_params: operation.params_in(ParamLocation::Path),
+            header_params: operation
+                .params_in(ParamLocation::Header)
+                .into_iter()
+                .map(|param| QueryParam {
+                    name: param.name.to_lowercase(),
+                    ..param
+                })
+                .collect(),
+            cookie_params: operation.params_in(ParamLocation::Cookie),
+            client: false,
+        }
+    }
+}
+
+impl From<&Operation> for printer::api::BindApiMethod {
+    fn from(operation: &Operation) -> Self {
+        use printer::api::{BindApiMethod, Extractor, SecurityRequirement};
+
+        let extractors = vec![
+            (ParamLocation::Path, Extractor::Path),
+            (ParamLocation::Query, Extractor::Query),
+            (ParamLocation::Header, Extractor::Headers),
+            (ParamLocation::Cookie, Extractor::Cookies),
+        ];
+
+        BindApiMethod {
+            method: operation.method.clone(),
+            path: operation.path.clone(),
+            name: operation.name.clone(),
+            request_body: operation.request_body.clone(),
+            security: operation
+                .security
+                .iter()
+                .map(|schemes| SecurityRequirement {
+                    schemes: schemes.clone(),
+                })
+                .collect(),
+            tags: operation.tags.clone(),
+            extractors: extractors
+                .into_iter()
+                .filter(|(location, _)| operation.has_params_in(*location))
+                .map(|(_, extractor)| extractor)
+                .collect(),
+        }
+    }
+}
+
+impl From<&Operation> for printer::client::ClientMethod {
+    fn from(operation: &Operation) -> Self {
+        printer::client::ClientMethod {
+            method: operation.method.clone(),
+            path: operation.path.clone(),
+            name: operation.name.clone(),
+            description: operation.description.clone(),
+            request_body: operation.request_body.clone(),
+            path_params: operation.params_in(ParamLocation::Path),
+            header_params: operation.params_in(ParamLocation::Header),
+            has_query: operation.has_params_in(ParamLocation::Query),
+            has_cookies: operation.has_params_in(ParamLocation::Cookie),
+        }
+    }
+}
+
+impl Operation {
+    fn params_in(&self, location: ParamLocation) -> Vec<printer::paths::QueryParam> {
+        self.params
+            .iter()
+            .filter(|param| param.location == location)
+            .map(|param| printer::paths::QueryParam {
+                name: param.name.clone(),
+                type_ref: param.type_name.clone(),
+                description: param.description.clone(),
+                required: param.required,
+            })
+            .collect()
+    }
+
+    fn has_params_in(&self, location: ParamLocation) -> bool {
+        self.params.iter().any(|param| param.location == location)
+    }
+}
+
+impl From<&OperationResponse> for printer::paths::StatusVariant {
+    fn from(response: &OperationResponse) -> Self {
+        use printer::paths::{ContentType, ResponseCookie, ResponseHeader, StatusVariant};
+
+        StatusVariant {
+            status: ResponseStatus::from_code(response.status)
+                .expect("status of the response is checked on parse"),
+            response_type_name: response.type_name.clone(),
+            description: response.description.clone(),
+            content_type: response.type_name.as_ref().map(|_| ContentType::Json),
+            x_variant_name: response.variant_name.clone(),
+            headers: response
+                .headers
+                .iter()
+                .map(|header| ResponseHeader {
+                    name: header.name.clone(),
+                    description: header.description.clone(),
+                    required: header.required,
+                    field_type: into_printer_type(header.field_type.clone()),
+                })
+                .collect(),
+            cookies: response
+                .cookies
+                .iter()
+                .map(|cookie| ResponseCookie {
+                    name: cookie.name.clone(),
+                    description: cookie.description.clone(),
+                    required: cookie.required,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Components;
+    use openapiv3::OpenAPI;
+
+    #[test]
+    fn unsupported_security_schemes_are_skipped() {
+        let api: OpenAPI = serde_yaml::from_str(
+            r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+security:
+  - digestAuth: []
+  - bearerAuth: []
+paths:
+  /session:
+    get:
+      operationId: sessionGet
+      responses:
+        200:
+          description: Session
+  /audit:
+    get:
+      operationId: auditGet
+      security:
+        - digestAuth: []
+      responses:
+        200:
+          description: Audit
+components:
+  securitySchemes:
+    bearerAuth:
+      type: http
+      scheme: bearer
+    digestAuth:
+      type: http
+      scheme: digest
+"###,
+        )
+        .unwrap();
+        let spec = api.components.unwrap();
+        let mut components = Components::default();
+
+        for (name, scheme) in spec.security_schemes.iter() {
+            let _ = components.parse_security_scheme(name, scheme);
+        }
+        for (path, item) in api.paths.paths.iter() {
+            components
+                .parse_path(path, item, &spec, &api.security)
+                .unwrap();
+        }
+
+        let operations = components
+            .operations
+            .iter()
+            .map(|operation| (operation.name.as_str(), operation.security.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            operations,
+            vec![("sessionGet", vec![vec![("bearerAuth".to_owned(), vec![])]])]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Base path of the api from `servers` of the specification
+
+use openapiv3::Server;
+
+/// Path of the server url with variables substituted, `https://{host}/api/{version}` to `/api/v2`
+///
+/// Variables take value from `overrides` first, then `default` of the variable.
+/// Empty string means routes are mounted at root
+pub fn server_path(server: &Server, overrides: &[(String, String)]) -> String {
+    let mut url = server.url.clone();
+
+    if let Some(variables) = &server.variables {
+        for (name, variable) in variables.iter() {
+            let value = overrides
+                .iter()
+                .find(|(key, _)| key == name)
+                .map_or(variable.default.as_str(), |(_, value)| value.as_str());
+
+            url = url.replace(&format!("{{{}}}", name), value);
+        }
+    }
+
+    // Scheme and host are not part of the path, `//host/path` has no scheme
+    let host_and_path = match url.find("://") {
+        Some(index) => Some(&url[index + 3..]),
+        None => url.strip_prefix("//"),
+    };
+
+    let path = match host_and_path {
+        Some(rest) => rest.find('/').map_or("", |index| &rest[index..]),
+        None => url.as_str(),
+    };
+
+    let path = path
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('.')
+        .trim_end_matches('/');
+
+    match path.is_empty() || path.starts_with('/') {
+        true => path.to_owned(),
+        false => format!("/{}", path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(source: &str) -> Server {
+        serde_yaml::from_str(source).unwrap()
+    }
+
+    #[test]
+    fn takes_path_of_url() {
+        let path = |url: &str| server_path(&server(&format!("url: '{}'", url)), &[]);
+
+        assert_eq!(path("https://host/api/v2"), "/api/v2");
+        assert_eq!(path("https://host:8080/api/v2/"), "/api/v2");
+        assert_eq!(path("//host/api"), "/api");
+        assert_eq!(path("https://host"), "");
+        assert_eq!(path("/api/v2"), "/api/v2");
+        assert_eq!(path("api"), "/api");
+        assert_eq!(path("/"), "");
+        assert_eq!(path("./"), "");
+    }
+
+    #[test]
+    fn substitutes_variables() {
+        let server = server(
+            r#"
+url: "{scheme}://{host}/api/{version}"
+variables:
+  scheme:
+    default: https
+  host:
+    default: example.com
+  version:
+    enum: [v1, v2]
+    default: v1
+"#,
+        );
+
+        assert_eq!(server_path(&server, &[]), "/api/v1");
+        assert_eq!(
+            server_path(&server, &[("version".to_owned(), "v2".to_owned())]),
+            "/api/v2"
+        );
+    }
+}
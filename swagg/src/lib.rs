@@ -1,12 +1,20 @@
 use openapiv3::OpenAPI;
+use std::path::PathBuf;
+use std::sync::Arc;
 
+mod builder;
 mod highway;
 mod printer;
+mod resolver;
+mod tree;
 
 #[cfg(test)]
 pub mod test;
 
+pub use builder::{tracked_files, Builder};
 use printer::Printable;
+pub use resolver::Resolver;
+pub use tree::write_files;
 
 /// Format for OpenAPI3 specification
 pub enum Format {
@@ -14,16 +22,97 @@ pub enum Format {
     Json,
 }
 
+/// What should be generated from specification
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// actix-web server api
+    #[default]
+    Server,
+    /// Async client for the api
+    Client,
+    /// Both server api and client
+    Both,
+}
+
+/// Settings of code generation
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub mode: Mode,
+
+    /// Name of the api struct, `info.title` is used by default
+    pub api_name: Option<String>,
+
+    /// Lints allowed for every generated module
+    pub lints: Vec<String>,
+
+    /// Keep generated code as is, without formatting
+    pub raw: bool,
+
+    /// Directory for `$ref` to other files, current directory by default
+    pub base_dir: Option<PathBuf>,
+
+    /// Loads documents for `$ref` by url, remote references fail without it
+    pub resolver: Option<Arc<dyn Resolver>>,
+
+    /// Generate `<Name>Request` and `<Name>Response` for objects with `readOnly` or `writeOnly` properties
+    pub views: bool,
+
+    /// Generate `<Api>Handlers` trait and `from_handlers` registering every operation
+    pub handlers: bool,
+
+    /// Embed specification in JSON, api can serve it with documentation page
+    pub embed_spec: bool,
+
+    /// Index in `servers`, routes are mounted under path of this server
+    pub server: usize,
+
+    /// Values of the server variables instead of their `default`
+    pub server_variables: Vec<(String, String)>,
+
+    /// Put paths, binders and handler traits of every tag into own module with its own api
+    pub split_by_tag: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            mode: Mode::default(),
+            api_name: None,
+            lints: vec!["dead_code".to_owned(), "unused_imports".to_owned()],
+            raw: false,
+            base_dir: None,
+            resolver: None,
+            views: false,
+            handlers: false,
+            embed_spec: false,
+            server: 0,
+            server_variables: vec![],
+            split_by_tag: false,
+        }
+    }
+}
+
 /// Describes convertation error
 #[derive(Debug)]
 pub enum Error {
     InvalidSource,
+    Io(std::path::PathBuf, std::io::Error),
+    OutDirNotSet,
+    UnknownServer(usize),
+    Embed(String),
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::InvalidSource => write!(f, "OpenAPI structure cannot be parsed"),
+            Self::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+            Self::OutDirNotSet => write!(
+                f,
+                "OUT_DIR is not set, builder should be called from build.rs"
+            ),
+            Self::UnknownServer(index) => write!(f, "Specification has no server {}", index),
+            Self::Embed(reason) => write!(f, "Specification cannot be embedded: {}", reason),
         }
     }
 }
@@ -32,51 +121,174 @@ impl std::error::Error for Error {}
 
 /// Convert source of OpenAPI3 specification to rust code in string representation
 pub fn to_string(source: &str, format: Format) -> Result<String, Error> {
-    let api: OpenAPI = match format {
-        Format::Yaml => serde_yaml::from_str(&source).map_err(|_| Error::InvalidSource)?,
-        Format::Json => serde_json::from_str(&source).map_err(|_| Error::InvalidSource)?,
+    to_string_with(source, format, &Settings::default())
+}
+
+/// Convert source of OpenAPI3 specification to rust code with custom settings
+pub fn to_string_with(source: &str, format: Format, settings: &Settings) -> Result<String, Error> {
+    let tokens = generate(source, format, settings)?;
+
+    match settings.raw {
+        true => Ok(tokens.to_string()),
+        false => Ok(format_tokens(tokens)),
+    }
+}
+
+/// Convert source of OpenAPI3 specification to module tree, `(path, content)` of every file
+///
+/// Paths are relative to the output directory, `mod.rs` declares `api`, `components` and `paths`,
+/// their modules are written to `components/schemas.rs`, `paths/session_get.rs` and so on
+pub fn to_files_with(
+    source: &str,
+    format: Format,
+    settings: &Settings,
+) -> Result<Vec<(PathBuf, String)>, Error> {
+    let tokens = generate(source, format, settings)?;
+    let file = syn::parse2::<syn::File>(tokens).map_err(|_| Error::InvalidSource)?;
+
+    Ok(tree::split(file, settings.raw))
+}
+
+fn generate(
+    source: &str,
+    format: Format,
+    settings: &Settings,
+) -> Result<proc_macro2::TokenStream, Error> {
+    Ok(generate_module(source, format, settings)?.print())
+}
+
+/// Printer tree of the generated code, every module is printed from it
+fn generate_module(
+    source: &str,
+    format: Format,
+    settings: &Settings,
+) -> Result<printer::GeneratedModule, Error> {
+    let mut root: serde_yaml::Value = match format {
+        Format::Yaml => serde_yaml::from_str(source).map_err(|_| Error::InvalidSource)?,
+        Format::Json => serde_json::from_str(source).map_err(|_| Error::InvalidSource)?,
     };
 
-    // eprintln!("{:#?}", api.components);
+    // Specification is embedded as written, before any rewrite
+    let written = match settings.embed_spec {
+        true => Some(root.clone()),
+        false => None,
+    };
 
-    let mut highway_components = highway::Components::new();
+    highway::rewrite_const(&mut root, false);
 
-    if let Some(components) = api.components {
-        // for (name, body) in components.request_bodies.iter() {
-        //     match body {
-        //         ReferenceOr::Item(body) => {
-        //             highway_components.parse_request_body(&name, &body);
-        //         }
-        //         ReferenceOr::Reference { reference } => {
-        //             log::info!("skipping request body reference {}", reference);
-        //         }
-        //     }
-        // }
+    let api: OpenAPI = serde_yaml::from_value(root.clone()).map_err(|_| Error::InvalidSource)?;
 
-        for (name, schema) in components.schemas.iter() {
-            if let Err(reason) = highway_components.parse_schema(&name, &schema) {
-                eprintln!("Failed {} {:#?}", name, reason);
-            }
+    // Specification without servers is served at `/`
+    let prefix = match api.servers.get(settings.server) {
+        Some(server) => highway::server_path(server, &settings.server_variables),
+        None if settings.server == 0 => String::new(),
+        None => return Err(Error::UnknownServer(settings.server)),
+    };
+
+    let documents = highway::Documents::new(
+        root,
+        settings.base_dir.clone().unwrap_or_default(),
+        settings.resolver.clone(),
+    );
+
+    // Embedded specification cannot refer to files and remote documents, they are inlined
+    let spec = match written {
+        Some(written) => {
+            let bundled = documents
+                .bundle(&written)
+                .map_err(|reason| Error::Embed(reason.to_string()))?;
+
+            Some(serde_json::to_string(&bundled).map_err(|_| Error::InvalidSource)?)
+        }
+        None => None,
+    };
+
+    let mut highway_components = highway::Components::with_documents(documents);
+
+    let components = api.components.unwrap_or_default();
+
+    for (name, schema) in components.schemas.iter() {
+        highway_components.declare_schema(name, schema);
+    }
+
+    for (name, schema) in components.schemas.iter() {
+        if let Err(reason) = highway_components.parse_schema(name, schema) {
+            log::warn!("Failed schema {}: {}", name, reason);
+        }
+    }
+
+    for (name, scheme) in components.security_schemes.iter() {
+        if let Err(reason) = highway_components.parse_security_scheme(name, scheme) {
+            log::warn!("Failed security scheme {}: {}", name, reason);
+        }
+    }
+
+    for (path, item) in api.paths.paths.iter() {
+        if let Err(reason) = highway_components.parse_path(path, item, &components, &api.security) {
+            log::warn!("Failed path {}: {}", path, reason);
         }
     }
 
-    println!("{:#?}", highway_components);
+    // Types of both sides never send `writeOnly`, client sends it with views
+    if settings.views || settings.mode == Mode::Both {
+        highway_components.split_views();
+    }
+    highway_components.apply_access(settings.mode);
+
+    log::trace!("{:#?}", highway_components);
 
     let mut generated: printer::GeneratedModule = highway_components.into();
 
-    generated.api.set_name(api.info.title);
-    generated.api.set_description(api.info.description);
+    let api_name = settings.api_name.clone().unwrap_or(api.info.title);
+    let description = api.info.description;
+
+    // Responses are decoded only by the client
+    let client = settings.mode != Mode::Server;
+    for path in generated.paths.paths.iter_mut() {
+        path.client = client;
+    }
+
+    generated.client = match client {
+        true => generated
+            .client
+            .take()
+            .map(|client| printer::client::ClientModule {
+                api_name: api_name.clone(),
+                description: description.clone(),
+                ..client
+            }),
+        false => None,
+    };
+    generated.server = settings.mode != Mode::Client;
+    generated.lints = settings.lints.clone();
+    generated.api.methods.handlers = settings.handlers;
+    generated.api.split_by_tag = settings.split_by_tag;
+
+    generated.api.set_name(api_name);
+    generated.api.set_description(description);
     generated
         .api
         .set_terms_of_service(api.info.terms_of_service);
+    generated.api.set_spec(spec);
+    generated.api.set_prefix(prefix);
 
-    Ok(format!("{}", generated.print()))
+    Ok(generated)
+}
+
+/// Pretty print generated code, raw code is returned if it cannot be parsed
+fn format_tokens(tokens: proc_macro2::TokenStream) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => tokens.to_string(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{to_string, Format};
-    use crate::test::pretty;
+    use super::{
+        generate_module, to_files_with, to_string, to_string_with, Format, Mode, Settings,
+    };
+    use crate::test::{pretty, shot};
     use insta::assert_snapshot;
 
     #[test]
@@ -125,24 +337,38 @@ components:
             - bar
         "###;
 
-        assert_snapshot!(pretty(to_string(&schema, Format::Yaml).unwrap()), @r###"
-        #![allow(dead_code, unused_imports)]
+        assert_snapshot!(pretty(to_string(schema, Format::Yaml).unwrap()), @r###"
+        #[allow(dead_code, unused_imports)]
         pub mod api {
-            #[doc = "Test api"]
+            /// Test api
             pub struct DemoApi {
                 api: actix_swagger::Api,
             }
             impl DemoApi {
                 pub fn new() -> Self {
                     Self {
-                        api: actix_swagger::Api::new(),
+                        api: actix_swagger::Api::new()
+                            .operation("/stub", actix_swagger::Method::GET),
                     }
                 }
+                /// Mount routes under `prefix` instead of path of the server
+                pub fn with_prefix(mut self, prefix: &str) -> Self {
+                    self.api = self.api.with_prefix(prefix);
+                    self
+                }
+                /// Panic on register when some operation has no handler, otherwise it answers 501
+                pub fn strict(mut self, strict: bool) -> Self {
+                    self.api = self.api.strict(strict);
+                    self
+                }
+                /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+                pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                    self.api.check()
+                }
             }
             impl Default for DemoApi {
                 fn default() -> Self {
-                    let api = Self::new();
-                    api
+                    Self::new()
                 }
             }
             impl actix_web::dev::HttpServiceFactory for DemoApi {
@@ -150,25 +376,48 @@ components:
                     self.api.register(config);
                 }
             }
-            use super::paths;
-            use actix_swagger::{Answer, Method};
             use actix_web::FromRequest;
+            use actix_swagger::{Answer, Method};
             use std::future::Future;
-            impl DemoApi {}
+            use super::paths;
+            impl DemoApi {
+                pub fn bind_stub<F, T, R>(mut self, handler: F) -> Self
+                where
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::stub::Response>,
+                        Future = R,
+                    >,
+                    T: FromRequest + 'static,
+                    R: Future<Output = Answer<'static, paths::stub::Response>> + 'static,
+                {
+                    self.api = self.api.bind("/stub", Method::GET, handler);
+                    self
+                }
+                /// Middleware and app data of `GET /stub`
+                pub fn route_stub(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/stub", Method::GET)],
+                    )
+                }
+            }
         }
+        #[allow(dead_code, unused_imports)]
         pub mod components {
             pub mod parameters {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod request_bodies {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod responses {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod schemas {
-                use serde::{Deserialize, Serialize};
-                #[doc = "Current user in a session"]
+                use serde::{Serialize, Deserialize};
+                /// Current user in a session
                 #[derive(Debug, Serialize, Deserialize)]
                 pub struct SessionUser {
                     #[serde(rename = "firstName")]
@@ -183,14 +432,1977 @@ components:
                     pub bar: i32,
                     pub baz: SessionUserInnerBaz,
                 }
-                #[derive(Debug, Serialize, Deserialize)]
+                #[derive(Debug, Default, Serialize, Deserialize)]
                 pub struct SessionUserInnerBaz {
                     pub demo: Option<String>,
                 }
             }
+            pub mod security_schemes {}
+        }
+        #[allow(dead_code, unused_imports)]
+        pub mod paths {
+            use super::components::{parameters, responses};
+            pub mod stub {
+                use super::responses;
+                use actix_swagger::{Answer, ContentType, StatusCode};
+                use serde::{Serialize, Deserialize};
+                #[derive(Debug, Serialize)]
+                #[serde(untagged)]
+                pub enum Response {
+                    /// Stub
+                    SeeOther,
+                }
+                impl Response {
+                    #[inline]
+                    pub fn to_answer(self) -> Answer<'static, Self> {
+                        let status = match self {
+                            Self::SeeOther => StatusCode::SEE_OTHER,
+                        };
+                        let content_type = match self {
+                            Self::SeeOther => None,
+                        };
+                        Answer::new(self).status(status).content_type(content_type)
+                    }
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_security_schemes_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  securitySchemes:
+    bearerAuth:
+      type: http
+      scheme: bearer
+      bearerFormat: JWT
+    basicAuth:
+      type: http
+      scheme: Basic
+    session:
+      type: apiKey
+      in: cookie
+      name: session-token
+      description: Session cookie
+    digest:
+      type: http
+      scheme: digest
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.api.methods), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl DemoApi {
+            /// Authenticate `bearerAuth` security scheme with `A`
+            pub fn authenticate_bearer_auth<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::BearerAuth,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "bearerAuth",
+                        actix_swagger::security::Scheme::Bearer,
+                        A::authenticate,
+                    );
+                self
+            }
+            /// Authenticate `basicAuth` security scheme with `A`
+            pub fn authenticate_basic_auth<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::BasicAuth,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "basicAuth",
+                        actix_swagger::security::Scheme::Basic,
+                        A::authenticate,
+                    );
+                self
+            }
+            /// Authenticate `session` security scheme with `A`
+            pub fn authenticate_session<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::Session,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "session",
+                        actix_swagger::security::Scheme::ApiKey {
+                            location: actix_swagger::security::ApiKeyLocation::Cookie,
+                            name: "session-token",
+                        },
+                        A::authenticate,
+                    );
+                self
+            }
+        }
+        "###);
+        assert_snapshot!(shot(module.components.security_schemes), @r###"
+        pub mod security_schemes {
+            pub trait BearerAuth: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::Bearer,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+            pub trait BasicAuth: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::Basic,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+            /// Session cookie
+            pub trait Session: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::ApiKey,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_root_security_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+security:
+  - bearerAuth: []
+paths:
+  /session:
+    get:
+      operationId: sessionGet
+      responses:
+        200:
+          description: Session
+    post:
+      operationId: sessionCreate
+      security: []
+      responses:
+        201:
+          description: Created
+components:
+  securitySchemes:
+    bearerAuth:
+      type: http
+      scheme: bearer
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.api.methods), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl DemoApi {
+            /// Authenticate `bearerAuth` security scheme with `A`
+            pub fn authenticate_bearer_auth<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::BearerAuth,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "bearerAuth",
+                        actix_swagger::security::Scheme::Bearer,
+                        A::authenticate,
+                    );
+                self
+            }
+            pub fn bind_session_get<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_get::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_get::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::GET, handler);
+                self.api = self.api.security("/session", Method::GET, &[&[("bearerAuth", &[])]]);
+                self
+            }
+            pub fn bind_session_create<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_create::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_create::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::POST, handler);
+                self
+            }
+            /// Middleware and app data of `GET /session`
+            pub fn route_session_get(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::GET)],
+                )
+            }
+            /// Middleware and app data of `POST /session`
+            pub fn route_session_create(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::POST)],
+                )
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_primitive_components_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    UserId:
+      description: Identifier of the user
+      type: integer
+    Email:
+      type: string
+    Ratio:
+      type: number
+    Active:
+      type: boolean
+    Users:
+      type: array
+      items:
+        $ref: "#/components/schemas/User"
+    User:
+      type: object
+      required: [id]
+      properties:
+        id:
+          $ref: "#/components/schemas/UserId"
+        email:
+          $ref: "#/components/schemas/Email"
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            /// Identifier of the user
+            pub type UserId = i32;
+            pub type Email = String;
+            pub type Ratio = f32;
+            pub type Active = bool;
+            pub type Users = Vec<User>;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct User {
+                pub id: UserId,
+                pub email: Option<Email>,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_boolean_const_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Enabled:
+      const: true
+    Archive:
+      type: object
+      required: [archived]
+      properties:
+        archived:
+          const: false
+        "###;
+        let settings = Settings {
+            mode: Mode::Client,
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+            pub struct Enabled;
+            impl Enabled {
+                pub const VALUE: bool = true;
+            }
+            impl Serialize for Enabled {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Self::VALUE.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Enabled {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    match bool::deserialize(deserializer)? == Self::VALUE {
+                        true => Ok(Self),
+                        false => {
+                            Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE)))
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Archive {
+                pub archived: ArchiveArchived,
+            }
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+            pub struct ArchiveArchived;
+            impl ArchiveArchived {
+                pub const VALUE: bool = false;
+            }
+            impl Serialize for ArchiveArchived {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Self::VALUE.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for ArchiveArchived {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    match bool::deserialize(deserializer)? == Self::VALUE {
+                        true => Ok(Self),
+                        false => {
+                            Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE)))
+                        }
+                    }
+                }
+            }
         }
+        "###);
+    }
+
+    #[test]
+    fn yaml_response_headers_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths:
+  /session:
+    post:
+      operationId: sessionCreate
+      responses:
+        201:
+          description: Session created
+          headers:
+            Set-Cookie:
+              description: Session token
+              required: true
+              schema:
+                type: string
+                example: session-token=abc; Path=/; HttpOnly
+            X-Request-Id:
+              $ref: "#/components/headers/RequestId"
+            X-Rate-Limit:
+              schema:
+                $ref: "#/components/schemas/RateLimit"
+            X-Scopes:
+              schema:
+                type: array
+                items:
+                  type: string
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Session"
+        303:
+          description: Already signed in
+          headers:
+            Location:
+              required: true
+              schema:
+                type: string
+components:
+  headers:
+    RequestId:
+      description: Identifier of the request
+      required: true
+      schema:
+        type: string
+  schemas:
+    RateLimit:
+      type: integer
+    Session:
+      type: object
+      properties:
+        user:
+          type: string
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.paths), @r###"
         pub mod paths {
             use super::components::{parameters, responses};
+            pub mod session_create {
+                use super::responses;
+                use actix_swagger::{Answer, ContentType, StatusCode};
+                use serde::{Serialize, Deserialize};
+                #[derive(Debug, Serialize)]
+                #[serde(untagged)]
+                pub enum Response {
+                    /// Session created
+                    #[serde(serialize_with = "actix_swagger::serialize_body")]
+                    Created(responses::SessionCreateCreated, CreatedHeaders),
+                    /// Already signed in
+                    #[serde(serialize_with = "actix_swagger::serialize_empty")]
+                    SeeOther(SeeOtherHeaders),
+                }
+                impl Response {
+                    #[inline]
+                    pub fn to_answer(self) -> Answer<'static, Self> {
+                        let status = match self {
+                            Self::Created(_, _) => StatusCode::CREATED,
+                            Self::SeeOther(_) => StatusCode::SEE_OTHER,
+                        };
+                        let content_type = match self {
+                            Self::Created(_, _) => Some(ContentType::Json),
+                            Self::SeeOther(_) => None,
+                        };
+                        let headers = match &self {
+                            Self::Created(_, headers) => headers.to_pairs(),
+                            Self::SeeOther(headers) => headers.to_pairs(),
+                        };
+                        Answer::new(self)
+                            .status(status)
+                            .content_type(content_type)
+                            .append_headers(headers)
+                    }
+                }
+                #[derive(Debug)]
+                pub struct CreatedHeaders {
+                    /// Identifier of the request
+                    pub x_request_id: String,
+                    pub x_rate_limit: Option<responses::SessionCreateCreatedXrateLimit>,
+                    pub x_scopes: Option<Vec<String>>,
+                    /// Session token
+                    pub session_token: actix_swagger::Cookie<'static>,
+                }
+                impl CreatedHeaders {
+                    /// Create `session-token` cookie
+                    pub fn session_token_cookie<V>(value: V) -> actix_swagger::Cookie<'static>
+                    where
+                        V: Into<std::borrow::Cow<'static, str>>,
+                    {
+                        actix_swagger::Cookie::new("session-token", value)
+                    }
+                    pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                        let mut pairs = vec![];
+                        let value = &self.x_request_id;
+                        pairs.push(("X-Request-Id", value.to_string()));
+                        if let Some(value) = &self.x_rate_limit {
+                            pairs.push(("X-Rate-Limit", value.to_string()));
+                        }
+                        if let Some(value) = &self.x_scopes {
+                            pairs
+                                .push((
+                                    "X-Scopes",
+                                    value
+                                        .iter()
+                                        .map(ToString::to_string)
+                                        .collect::<Vec<_>>()
+                                        .join(","),
+                                ));
+                        }
+                        pairs.push(("Set-Cookie", self.session_token.to_string()));
+                        pairs
+                    }
+                }
+                #[derive(Debug)]
+                pub struct SeeOtherHeaders {
+                    pub location: String,
+                }
+                impl SeeOtherHeaders {
+                    pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                        let mut pairs = vec![];
+                        let value = &self.location;
+                        pairs.push(("Location", value.to_string()));
+                        pairs
+                    }
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_client_mode_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+  description: Test api
+paths: {}
+        "###;
+        let settings = Settings {
+            mode: Mode::Client,
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert!(!module.server);
+        assert_snapshot!(shot(module.client.unwrap()), @r###"
+        pub mod client {
+            use super::components::{parameters, request_bodies};
+            use super::paths;
+            use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+            /// Test api
+            pub struct DemoApiClient<C = actix_swagger::client::AwcClient> {
+                base_url: String,
+                http: C,
+            }
+            impl DemoApiClient {
+                pub fn new<U: Into<String>>(base_url: U) -> Self {
+                    Self::with_http(base_url, Default::default())
+                }
+            }
+            impl<C: HttpClient> DemoApiClient<C> {
+                pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_owned();
+                    Self { base_url, http }
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_operations_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths:
+  /users:
+    get:
+      operationId: listUsers
+      responses:
+        200:
+          description: Users
+    post:
+      operationId: createUser
+      responses:
+        201:
+          description: Created
+  /users/{id}:
+    delete:
+      operationId: deleteUser
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: integer
+      responses:
+        204:
+          description: Deleted
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.api.structure), @r###"
+        pub struct DemoApi {
+            api: actix_swagger::Api,
+        }
+        impl DemoApi {
+            pub fn new() -> Self {
+                Self {
+                    api: actix_swagger::Api::new()
+                        .operation("/users", actix_swagger::Method::GET)
+                        .operation("/users", actix_swagger::Method::POST)
+                        .operation("/users/{id}", actix_swagger::Method::DELETE),
+                }
+            }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
+        }
+        impl Default for DemoApi {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+        impl actix_web::dev::HttpServiceFactory for DemoApi {
+            fn register(self, config: &mut actix_web::dev::AppService) {
+                self.api.register(config);
+            }
+        }
+        "###);
+        assert_snapshot!(shot(module.api.methods), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl DemoApi {
+            pub fn bind_list_users<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::list_users::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::list_users::Response>> + 'static,
+            {
+                self.api = self.api.bind("/users", Method::GET, handler);
+                self
+            }
+            pub fn bind_create_user<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::create_user::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::create_user::Response>> + 'static,
+            {
+                self.api = self.api.bind("/users", Method::POST, handler);
+                self
+            }
+            pub fn bind_delete_user<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::delete_user::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::delete_user::Response>> + 'static,
+            {
+                self.api = self.api.bind("/users/{id}", Method::DELETE, handler);
+                self
+            }
+            /// Middleware and app data of `GET /users`
+            pub fn route_list_users(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/users", Method::GET)],
+                )
+            }
+            /// Middleware and app data of `POST /users`
+            pub fn route_create_user(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/users", Method::POST)],
+                )
+            }
+            /// Middleware and app data of `DELETE /users/{id}`
+            pub fn route_delete_user(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/users/{id}", Method::DELETE)],
+                )
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_handlers_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths:
+  /users:
+    post:
+      operationId: createUser
+      tags: [users]
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/User"
+      responses:
+        201:
+          description: Created
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/User"
+components:
+  schemas:
+    User:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+        "###;
+        let settings = Settings {
+            handlers: true,
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.api.methods), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use std::rc::Rc;
+        use super::paths;
+        impl DemoApi {
+            /// Request body - super::requst_bodies::CreateUserRequestBody
+            pub fn bind_create_user<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::create_user::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::create_user::Response>> + 'static,
+            {
+                self.api = self.api.bind("/users", Method::POST, handler);
+                self
+            }
+            /// Middleware and app data of `POST /users`
+            pub fn route_create_user(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/users", Method::POST)],
+                )
+            }
+            /// Middleware and app data of operations tagged `users`
+            pub fn tag_users(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/users", Method::POST)],
+                )
+            }
+        }
+        /// Handler for every operation of `DemoApi`, use it with `DemoApi::from_handlers`
+        pub trait DemoApiHandlers: 'static {
+            /// POST /users
+            fn create_user(
+                &self,
+                request: actix_web::HttpRequest,
+                body: actix_web::web::Json<
+                    super::components::request_bodies::CreateUserRequestBody,
+                >,
+            ) -> impl Future<Output = Answer<'static, paths::create_user::Response>>;
+        }
+        impl DemoApi {
+            /// Register every operation, routes call methods of `handlers`
+            pub fn from_handlers<H: DemoApiHandlers>(handlers: H) -> Self {
+                let handlers = Rc::new(handlers);
+                let mut api = Self::new();
+                let handler = Rc::clone(&handlers);
+                api.api = api
+                    .api
+                    .bind(
+                        "/users",
+                        Method::POST,
+                        move |
+                            request: actix_web::HttpRequest,
+                            body: actix_web::web::Json<
+                                super::components::request_bodies::CreateUserRequestBody,
+                            >|
+                        {
+                            let handlers = Rc::clone(&handler);
+                            async move { handlers.create_user(request, body).await }
+                        },
+                    );
+                api
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_client_operations_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths:
+  /users/{id}:
+    post:
+      operationId: updateUser
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: integer
+        - name: notify
+          in: query
+          schema:
+            type: boolean
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/User"
+      responses:
+        200:
+          description: Updated user
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/User"
+        404:
+          description: User not found
+components:
+  schemas:
+    User:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+        "###;
+        let settings = Settings {
+            mode: Mode::Client,
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.client.unwrap()), @r###"
+        pub mod client {
+            use super::components::{parameters, request_bodies};
+            use super::paths;
+            use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+            pub struct DemoApiClient<C = actix_swagger::client::AwcClient> {
+                base_url: String,
+                http: C,
+            }
+            impl DemoApiClient {
+                pub fn new<U: Into<String>>(base_url: U) -> Self {
+                    Self::with_http(base_url, Default::default())
+                }
+            }
+            impl<C: HttpClient> DemoApiClient<C> {
+                pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_owned();
+                    Self { base_url, http }
+                }
+                pub async fn update_user(
+                    &self,
+                    id: &parameters::UpdateUserId,
+                    query: &paths::update_user::QueryParams,
+                    body: &request_bodies::UpdateUserRequestBody,
+                ) -> Result<paths::update_user::Response, ClientError> {
+                    let request = ClientRequest::new(
+                        Method::POST,
+                        format!(
+                            "{}/users/{}", self.base_url, actix_swagger::client::path_segment(id)
+                            ?
+                        ),
+                    );
+                    let request = request.query(query)?;
+                    let request = request.json(body)?;
+                    let response = self.http.send(request).await?;
+                    paths::update_user::Response::from_response(&response)
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_external_references_prints() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        std::fs::create_dir(dir.path().join("models")).unwrap();
+        std::fs::write(
+            dir.path().join("models/user.yaml"),
+            r###"
+User:
+  type: object
+  required: [id]
+  properties:
+    id:
+      type: string
+    group:
+      $ref: "./group.yaml#/Group"
+    avatar:
+      $ref: "https://example.com/media.yaml#/Image"
+"###,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("models/group.yaml"),
+            r###"
+Group:
+  type: object
+  properties:
+    owner:
+      $ref: "./user.yaml#/User"
+"###,
+        )
+        .unwrap();
+
+        let mut remote = std::collections::HashMap::new();
+        remote.insert(
+            "https://example.com/media.yaml".to_owned(),
+            "Image:\n  type: object\n  properties:\n    url:\n      type: string\n".to_owned(),
+        );
+
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Account:
+      $ref: "./models/user.yaml#/User"
+    Session:
+      type: object
+      properties:
+        user:
+          $ref: "#/components/schemas/Account"
+        "###;
+        let settings = Settings {
+            base_dir: Some(dir.path().to_path_buf()),
+            resolver: Some(std::sync::Arc::new(remote)),
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Account {
+                pub id: String,
+                pub group: Option<Box<Group>>,
+                pub avatar: Option<Image>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Image {
+                pub url: Option<String>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Group {
+                pub owner: Option<Box<Account>>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Session {
+                pub user: Option<Account>,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_recursive_schemas_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    TreeNode:
+      type: object
+      required: [parent]
+      properties:
+        children:
+          type: array
+          items:
+            $ref: "#/components/schemas/TreeNode"
+        parent:
+          $ref: "#/components/schemas/TreeNode"
+    Author:
+      type: object
+      properties:
+        latest:
+          $ref: "#/components/schemas/Post"
+    Post:
+      type: object
+      properties:
+        author:
+          $ref: "#/components/schemas/Author"
+        tree:
+          $ref: "#/components/schemas/TreeNode"
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct TreeNode {
+                pub children: Option<Vec<TreeNode>>,
+                pub parent: Box<TreeNode>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Author {
+                pub latest: Option<Box<Post>>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Post {
+                pub author: Option<Box<Author>>,
+                pub tree: Option<TreeNode>,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_enums_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Status:
+      type: string
+      enum: [a, b, null]
+      x-enum-varnames: [active, blocked]
+    Priority:
+      type: integer
+      enum: [1, 2, 3]
+      x-enum-varnames: [low, medium]
+    Ratio:
+      type: number
+      enum: [0.5, 1.5]
+    Kind:
+      type: string
+      enum: [user]
+    Version:
+      const: 2
+    Task:
+      type: object
+      properties:
+        mode:
+          type: string
+          enum: [fast, slow]
+        const:
+          type: boolean
+"###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub enum Status {
+                #[serde(rename = "a")]
+                Active,
+                #[serde(rename = "b")]
+                Blocked,
+            }
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #[repr(i64)]
+            pub enum Priority {
+                Low = 1,
+                Medium = 2,
+                Value3 = 3,
+            }
+            impl Serialize for Priority {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let value: i64 = match self {
+                        Self::Low => 1,
+                        Self::Medium => 2,
+                        Self::Value3 => 3,
+                    };
+                    value.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Priority {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = i64::deserialize(deserializer)?;
+                    if value == 1 {
+                        return Ok(Self::Low);
+                    }
+                    if value == 2 {
+                        return Ok(Self::Medium);
+                    }
+                    if value == 3 {
+                        return Ok(Self::Value3);
+                    }
+                    Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+                }
+            }
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub enum Ratio {
+                Value0Point5,
+                Value1Point5,
+            }
+            impl Serialize for Ratio {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let value: f64 = match self {
+                        Self::Value0Point5 => 0.5,
+                        Self::Value1Point5 => 1.5,
+                    };
+                    value.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Ratio {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = f64::deserialize(deserializer)?;
+                    if value == 0.5 {
+                        return Ok(Self::Value0Point5);
+                    }
+                    if value == 1.5 {
+                        return Ok(Self::Value1Point5);
+                    }
+                    Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+                }
+            }
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+            pub struct Kind;
+            impl Kind {
+                pub const VALUE: &'static str = "user";
+            }
+            impl Serialize for Kind {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Self::VALUE.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Kind {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    match String::deserialize(deserializer)? == Self::VALUE {
+                        true => Ok(Self),
+                        false => {
+                            Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE)))
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+            pub struct Version;
+            impl Version {
+                pub const VALUE: i64 = 2;
+            }
+            impl Serialize for Version {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Self::VALUE.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for Version {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    match i64::deserialize(deserializer)? == Self::VALUE {
+                        true => Ok(Self),
+                        false => {
+                            Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE)))
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Task {
+                pub mode: Option<TaskMode>,
+                pub r#const: Option<bool>,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub enum TaskMode {
+                #[serde(rename = "fast")]
+                Fast,
+                #[serde(rename = "slow")]
+                Slow,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_empty_object_defaults_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Settings:
+      type: object
+      properties:
+        filter:
+          type: object
+          required: [query]
+          properties:
+            query:
+              type: string
+              default: ""
+            tag:
+              type: string
+          default: {}
+        pagination:
+          type: object
+          required: [page]
+          properties:
+            page:
+              type: integer
+          default: {}
+        sorting:
+          type: object
+          required: [order]
+          properties:
+            order:
+              type: string
+              default: 1
+          default: {}
+"###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Settings {
+                #[serde(default = "Settings::default_filter")]
+                pub filter: SettingsFilter,
+                pub pagination: Option<SettingsPagination>,
+                pub sorting: Option<SettingsSorting>,
+            }
+            impl Settings {
+                pub fn default_filter() -> SettingsFilter {
+                    Default::default()
+                }
+            }
+            impl Default for Settings {
+                fn default() -> Self {
+                    Self {
+                        filter: Self::default_filter(),
+                        pagination: None,
+                        sorting: None,
+                    }
+                }
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct SettingsFilter {
+                #[serde(default = "SettingsFilter::default_query")]
+                pub query: String,
+                pub tag: Option<String>,
+            }
+            impl SettingsFilter {
+                pub fn default_query() -> String {
+                    "".to_owned()
+                }
+            }
+            impl Default for SettingsFilter {
+                fn default() -> Self {
+                    Self {
+                        query: Self::default_query(),
+                        tag: None,
+                    }
+                }
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct SettingsPagination {
+                pub page: i32,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct SettingsSorting {
+                pub order: String,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_defaults_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Search:
+      type: object
+      properties:
+        query:
+          type: string
+          default: ""
+        limit:
+          type: integer
+          default: 20
+        ratio:
+          type: number
+          default: 1
+        exact:
+          type: boolean
+          default: false
+        priority:
+          type: integer
+          enum: [1, 2]
+          x-enum-varnames: [low, high]
+          default: 2
+        order:
+          type: string
+          enum: [asc, desc]
+          default: desc
+        tags:
+          type: array
+          items:
+            type: string
+          default: []
+        page:
+          type: integer
+          default: first
+        cursor:
+          type: string
+    Pagination:
+      type: object
+      required: [page]
+      properties:
+        page:
+          type: integer
+"###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Search {
+                #[serde(default = "Search::default_query")]
+                pub query: String,
+                #[serde(default = "Search::default_limit")]
+                pub limit: i32,
+                #[serde(default = "Search::default_ratio")]
+                pub ratio: f32,
+                #[serde(default = "Search::default_exact")]
+                pub exact: bool,
+                #[serde(default = "Search::default_priority")]
+                pub priority: SearchPriority,
+                #[serde(default = "Search::default_order")]
+                pub order: SearchOrder,
+                #[serde(default = "Search::default_tags")]
+                pub tags: Vec<String>,
+                pub page: Option<i32>,
+                pub cursor: Option<String>,
+            }
+            impl Search {
+                pub fn default_query() -> String {
+                    "".to_owned()
+                }
+                pub fn default_limit() -> i32 {
+                    20
+                }
+                pub fn default_ratio() -> f32 {
+                    1.0
+                }
+                pub fn default_exact() -> bool {
+                    false
+                }
+                pub fn default_priority() -> SearchPriority {
+                    SearchPriority::High
+                }
+                pub fn default_order() -> SearchOrder {
+                    SearchOrder::Desc
+                }
+                pub fn default_tags() -> Vec<String> {
+                    Default::default()
+                }
+            }
+            impl Default for Search {
+                fn default() -> Self {
+                    Self {
+                        query: Self::default_query(),
+                        limit: Self::default_limit(),
+                        ratio: Self::default_ratio(),
+                        exact: Self::default_exact(),
+                        priority: Self::default_priority(),
+                        order: Self::default_order(),
+                        tags: Self::default_tags(),
+                        page: None,
+                        cursor: None,
+                    }
+                }
+            }
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            #[repr(i64)]
+            pub enum SearchPriority {
+                Low = 1,
+                High = 2,
+            }
+            impl Serialize for SearchPriority {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let value: i64 = match self {
+                        Self::Low => 1,
+                        Self::High => 2,
+                    };
+                    value.serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for SearchPriority {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = i64::deserialize(deserializer)?;
+                    if value == 1 {
+                        return Ok(Self::Low);
+                    }
+                    if value == 2 {
+                        return Ok(Self::High);
+                    }
+                    Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+                }
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub enum SearchOrder {
+                #[serde(rename = "asc")]
+                Asc,
+                #[serde(rename = "desc")]
+                Desc,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Pagination {
+                pub page: i32,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_read_only_server_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      required: [id, email, password]
+      properties:
+        id:
+          type: integer
+          readOnly: true
+        email:
+          type: string
+        password:
+          type: string
+          writeOnly: true
+"###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct User {
+                pub id: Option<i32>,
+                pub email: String,
+                #[serde(skip_serializing)]
+                pub password: String,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_both_mode_nested_views_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      required: [id, password]
+      properties:
+        id:
+          type: integer
+          readOnly: true
+        password:
+          type: string
+          writeOnly: true
+    Team:
+      type: object
+      properties:
+        owner:
+          $ref: "#/components/schemas/User"
+        members:
+          type: array
+          items:
+            $ref: "#/components/schemas/User"
+"###;
+
+        let settings = Settings {
+            mode: Mode::Both,
+            ..Settings::default()
+        };
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct User {
+                pub id: Option<i32>,
+                #[serde(skip_serializing)]
+                pub password: Option<String>,
+            }
+            /// `User` in requests, read-only properties are left out
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct UserRequest {
+                pub password: String,
+            }
+            /// `User` in responses, write-only properties are left out
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct UserResponse {
+                pub id: i32,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Team {
+                pub owner: Option<User>,
+                pub members: Option<Vec<User>>,
+            }
+            /// `Team` in requests, read-only properties are left out
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct TeamRequest {
+                pub owner: Option<UserRequest>,
+                pub members: Option<Vec<UserRequest>>,
+            }
+            /// `Team` in responses, write-only properties are left out
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct TeamResponse {
+                pub owner: Option<UserResponse>,
+                pub members: Option<Vec<UserResponse>>,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn yaml_read_only_views_client_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      required: [id, email, password]
+      properties:
+        id:
+          type: integer
+          readOnly: true
+        email:
+          type: string
+        password:
+          type: string
+          writeOnly: true
+"###;
+
+        let settings = Settings {
+            mode: Mode::Client,
+            views: true,
+            ..Settings::default()
+        };
+
+        let module = generate_module(schema, Format::Yaml, &settings).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct User {
+                #[serde(skip_serializing)]
+                pub id: i32,
+                pub email: String,
+                pub password: Option<String>,
+            }
+            /// `User` in requests, read-only properties are left out
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct UserRequest {
+                pub email: String,
+                pub password: String,
+            }
+            /// `User` in responses, write-only properties are left out
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct UserResponse {
+                pub id: i32,
+                pub email: String,
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn writes_module_tree() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        name:
+          type: string
+"###;
+
+        let files = to_files_with(schema, Format::Yaml, &Settings::default()).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect();
+
+        assert_snapshot!(names.join("\n"), @r###"
+        mod.rs
+        api.rs
+        components/mod.rs
+        components/parameters.rs
+        components/request_bodies.rs
+        components/responses.rs
+        components/schemas.rs
+        components/security_schemes.rs
+        paths.rs
+        "###);
+        assert_snapshot!(pretty(files[0].1.clone()), @r###"
+        #[allow(dead_code, unused_imports)]
+        pub mod api;
+        #[allow(dead_code, unused_imports)]
+        pub mod components;
+        #[allow(dead_code, unused_imports)]
+        pub mod paths;
+        "###);
+
+        let (_, schemas) = files
+            .iter()
+            .find(|(path, _)| path.ends_with("components/schemas.rs"))
+            .unwrap();
+        assert_snapshot!(pretty(schemas.clone()), @r###"
+        use serde::{Serialize, Deserialize};
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct User {
+            pub name: Option<String>,
+        }
+        "###);
+    }
+
+    #[test]
+    fn mounts_api_under_server_path() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+servers:
+  - url: https://example.com/api/{version}
+    variables:
+      version:
+        default: v1
+  - url: /staging
+paths: {}
+"###;
+
+        let code = to_string(schema, Format::Yaml).unwrap();
+        assert!(code.contains(r#"actix_swagger::Api::new().with_prefix("/api/v1")"#));
+
+        let settings = Settings {
+            server_variables: vec![("version".to_owned(), "v2".to_owned())],
+            ..Settings::default()
+        };
+        let code = to_string_with(schema, Format::Yaml, &settings).unwrap();
+        assert!(code.contains(r#"actix_swagger::Api::new().with_prefix("/api/v2")"#));
+
+        let settings = Settings {
+            server: 1,
+            ..Settings::default()
+        };
+        let code = to_string_with(schema, Format::Yaml, &settings).unwrap();
+        assert!(code.contains(r#"actix_swagger::Api::new().with_prefix("/staging")"#));
+
+        let settings = Settings {
+            server: 2,
+            ..Settings::default()
+        };
+        assert!(matches!(
+            to_string_with(schema, Format::Yaml, &settings),
+            Err(crate::Error::UnknownServer(2))
+        ));
+    }
+
+    #[test]
+    fn embeds_spec_as_written() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Version:
+      const: 2
+"###;
+
+        let settings = Settings {
+            embed_spec: true,
+            raw: true,
+            ..Settings::default()
+        };
+        let code = to_string_with(schema, Format::Yaml, &settings).unwrap();
+
+        assert!(code.contains(
+            r#"OPENAPI : & 'static str = "{\"openapi\":\"3.0.1\",\"info\":{\"title\":\"Demo API.\",\"version\":\"0.1.0\"},\"paths\":{},\"components\":{\"schemas\":{\"Version\":{\"const\":2}}}}""#
+        ));
+    }
+
+    #[test]
+    fn embeds_spec_with_external_refs_inlined() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        std::fs::write(
+            dir.path().join("user.yaml"),
+            "User:\n  type: object\n  properties:\n    group:\n      $ref: \"#/Group\"\nGroup:\n  type: string\n",
+        )
+        .unwrap();
+
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    User:
+      $ref: "./user.yaml#/User"
+    Owner:
+      $ref: "#/components/schemas/User"
+"###;
+
+        let settings = Settings {
+            embed_spec: true,
+            raw: true,
+            base_dir: Some(dir.path().to_path_buf()),
+            ..Settings::default()
+        };
+        let code = to_string_with(schema, Format::Yaml, &settings).unwrap();
+
+        assert!(code.contains(
+            r##"\"User\":{\"type\":\"object\",\"properties\":{\"group\":{\"type\":\"string\"}}},\"Owner\":{\"$ref\":\"#/components/schemas/User\"}"##
+        ));
+    }
+
+    #[test]
+    fn circular_external_refs_cannot_be_embedded() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        std::fs::write(
+            dir.path().join("node.yaml"),
+            "Node:\n  type: object\n  properties:\n    next:\n      $ref: \"#/Node\"\n",
+        )
+        .unwrap();
+
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    Node:
+      $ref: "./node.yaml#/Node"
+"###;
+
+        let settings = Settings {
+            embed_spec: true,
+            base_dir: Some(dir.path().to_path_buf()),
+            ..Settings::default()
+        };
+
+        assert!(matches!(
+            to_string_with(schema, Format::Yaml, &settings),
+            Err(crate::Error::Embed(_))
+        ));
+    }
+
+    #[test]
+    fn yaml_inline_type_names_prints() {
+        let schema = r###"
+openapi: 3.0.1
+info:
+  title: Demo API.
+  version: 0.1.0
+paths: {}
+components:
+  schemas:
+    SessionUser:
+      type: object
+      properties:
+        inner:
+          type: object
+          properties:
+            id:
+              type: string
+        avatar:
+          title: user image
+          type: object
+          properties:
+            url:
+              type: string
+        links:
+          type: array
+          items:
+            x-rust-type-name: link
+            type: object
+            properties:
+              href:
+                type: string
+    SessionUserInner:
+      type: object
+      properties:
+        bar:
+          type: string
+    Photo:
+      title: UserImage
+      x-rust-type-name: Picture
+      type: object
+      properties:
+        user:
+          $ref: "#/components/schemas/SessionUser"
+        "###;
+
+        let module = generate_module(schema, Format::Yaml, &Settings::default()).unwrap();
+
+        assert_snapshot!(shot(module.components.schemas), @r###"
+        pub mod schemas {
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct SessionUser {
+                pub inner: Option<SessionUserInner2>,
+                pub avatar: Option<UserImage>,
+                pub links: Option<Vec<Link>>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct SessionUserInner2 {
+                pub id: Option<String>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct UserImage {
+                pub url: Option<String>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Link {
+                pub href: Option<String>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct SessionUserInner {
+                pub bar: Option<String>,
+            }
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct Picture {
+                pub user: Option<SessionUser>,
+            }
         }
         "###);
     }
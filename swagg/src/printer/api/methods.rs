@@ -1,72 +1,242 @@
 use super::structure::to_struct_name;
-use crate::printer::Printable;
-use inflections::Inflect;
+use crate::printer::{
+    components::security_schemes::SecurityScheme,
+    ident::{to_pascal_ident, to_snake_ident, to_type_ident, unraw},
+    print_doc, Printable,
+};
+use indexmap::IndexMap;
 use quote::{format_ident, quote};
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub enum HttpMethod {
     Delete,
     Get,
+    Head,
+    Options,
     Patch,
     Post,
     Put,
+    Trace,
 }
 
-impl ToString for HttpMethod {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let method = match self {
             HttpMethod::Delete => "DELETE",
             HttpMethod::Get => "GET",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Options => "OPTIONS",
             HttpMethod::Patch => "PATCH",
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
-        }
-        .to_owned()
+            HttpMethod::Trace => "TRACE",
+        };
+
+        write!(f, "{}", method)
+    }
+}
+
+/// Security requirement object, every scheme in it must be satisfied with its scopes
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityRequirement {
+    pub schemes: Vec<(String, Vec<String>)>,
+}
+
+impl Printable for SecurityRequirement {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let schemes = self.schemes.iter().map(|(name, scopes)| {
+            quote! { (#name, &[#(#scopes),*]) }
+        });
+
+        quote! { &[#(#schemes),*] }
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BindApiMethod {
     pub method: HttpMethod,
     pub path: String,
     pub name: String,
     pub request_body: Option<String>,
+
+    /// Alternatives of security requirements, any of them authorizes request
+    pub security: Vec<SecurityRequirement>,
+
+    /// Tags of the operation, each tag gets its own group of operations
+    pub tags: Vec<String>,
+
+    /// Types of `paths::<name>` parameters are extracted with, passed to handlers in this order
+    pub extractors: Vec<Extractor>,
+}
+
+/// Parameters of the operation in one location, extracted by `paths::<name>::<Extractor>`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum Extractor {
+    Path,
+    Query,
+    Headers,
+    Cookies,
+}
+
+impl Extractor {
+    /// Name of the handler argument and of the type in `paths::<name>`
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Extractor::Path => ("path", "Path"),
+            Extractor::Query => ("query", "Query"),
+            Extractor::Headers => ("headers", "Headers"),
+            Extractor::Cookies => ("cookies", "Cookies"),
+        }
+    }
 }
 
 impl Printable for BindApiMethod {
     fn print(&self) -> proc_macro2::TokenStream {
         let request_path = self.path.clone();
         let http_method = format_ident!("{}", self.method.to_string());
-        let path_name = format_ident!("{}", self.name.to_snake_case());
-        let bind_method_name = format_ident!("bind_{}", self.name.to_snake_case());
+        let path_name = to_snake_ident(&self.name);
+        let bind_method_name = format_ident!("bind_{}", unraw(&path_name));
         let request_body_stream = match &self.request_body {
             Some(request_body) => {
-                let body = request_body.to_pascal_case();
+                let body = to_pascal_ident(request_body);
                 let doc = format!("Request body - super::requst_bodies::{}", body);
-                quote! { #[doc = #doc] }
+                print_doc(&doc)
             }
             None => quote! {},
         };
+        let security = match self.security.is_empty() {
+            true => quote! {},
+            false => {
+                let alternatives = self.security.iter().map(|r| r.print());
+
+                quote! {
+                    self.api = self.api.security(#request_path, Method::#http_method, &[#(#alternatives),*]);
+                }
+            }
+        };
 
         quote! {
             #request_body_stream
             pub fn #bind_method_name<F, T, R>(mut self, handler: F) -> Self
             where
-                F: Factory<T, R, Answer<'static, paths::#path_name::Response>>,
+                F: actix_web::Handler<T, Output = Answer<'static, paths::#path_name::Response>, Future = R>,
                 T: FromRequest + 'static,
                 R: Future<Output = Answer<'static, paths::#path_name::Response>> + 'static,
             {
-                self.api = self.api.bind(#request_path.to_owned(), Method::#http_method, handler);
+                self.api = self.api.bind(#request_path, Method::#http_method, handler);
+                #security
                 self
             }
         }
     }
 }
 
+impl BindApiMethod {
+    fn print_operation(&self) -> proc_macro2::TokenStream {
+        let request_path = self.path.clone();
+        let http_method = format_ident!("{}", self.method.to_string());
+
+        quote! { (#request_path, Method::#http_method) }
+    }
+
+    fn print_security(&self) -> proc_macro2::TokenStream {
+        let request_path = self.path.clone();
+        let http_method = format_ident!("{}", self.method.to_string());
+
+        match self.security.is_empty() {
+            true => quote! {},
+            false => {
+                let alternatives = self.security.iter().map(|r| r.print());
+
+                quote! {
+                    api.api = api.api.security(#request_path, Method::#http_method, &[#(#alternatives),*]);
+                }
+            }
+        }
+    }
+
+    fn print_request_body_type(&self) -> Option<proc_macro2::TokenStream> {
+        self.request_body.as_ref().map(|request_body| {
+            let body = to_pascal_ident(request_body);
+            quote! { actix_web::web::Json<super::components::request_bodies::#body> }
+        })
+    }
+
+    /// Arguments of the handler after `request`: extracted parameters and request body
+    fn print_handler_arguments(&self) -> Vec<(proc_macro2::Ident, proc_macro2::TokenStream)> {
+        let path_name = to_snake_ident(&self.name);
+
+        let mut arguments: Vec<_> = self
+            .extractors
+            .iter()
+            .map(|extractor| {
+                let (argument, type_name) = extractor.names();
+                let type_name = format_ident!("{}", type_name);
+
+                (
+                    format_ident!("{}", argument),
+                    quote! { paths::#path_name::#type_name },
+                )
+            })
+            .collect();
+
+        if let Some(body_type) = self.print_request_body_type() {
+            arguments.push((format_ident!("body"), body_type));
+        }
+
+        arguments
+    }
+
+    /// Method of the handlers trait, answers for the operation
+    fn print_handler_signature(&self) -> proc_macro2::TokenStream {
+        let path_name = to_snake_ident(&self.name);
+        let doc = print_doc(&format!("{} {}", self.method, self.path));
+        let arguments = self
+            .print_handler_arguments()
+            .into_iter()
+            .map(|(name, argument_type)| quote! { , #name: #argument_type });
+
+        quote! {
+            #doc
+            fn #path_name(
+                &self,
+                request: actix_web::HttpRequest #(#arguments)*
+            ) -> impl Future<Output = Answer<'static, paths::#path_name::Response>>;
+        }
+    }
+
+    /// Route calling the method of the handlers
+    fn print_handler_route(&self) -> proc_macro2::TokenStream {
+        let request_path = self.path.clone();
+        let http_method = format_ident!("{}", self.method.to_string());
+        let path_name = to_snake_ident(&self.name);
+        let security = self.print_security();
+
+        let handler_arguments = self.print_handler_arguments();
+        let arguments = handler_arguments
+            .iter()
+            .map(|(name, argument_type)| quote! { , #name: #argument_type });
+        let values = handler_arguments.iter().map(|(name, _)| quote! { , #name });
+
+        quote! {
+            let handler = Rc::clone(&handlers);
+            api.api = api.api.bind(#request_path, Method::#http_method, move |request: actix_web::HttpRequest #(#arguments)*| {
+                let handlers = Rc::clone(&handler);
+                async move { handlers.#path_name(request #(#values)*).await }
+            });
+            #security
+        }
+    }
+}
+
 pub struct ImplApi {
     pub api_name: String,
     pub methods: Vec<BindApiMethod>,
+    pub security_schemes: Vec<SecurityScheme>,
+
+    /// Generate trait with handler for every operation and `from_handlers` constructor
+    pub handlers: bool,
 }
 
 impl Default for ImplApi {
@@ -74,24 +244,154 @@ impl Default for ImplApi {
         Self {
             api_name: "Api".to_owned(),
             methods: vec![],
+            security_schemes: vec![],
+            handlers: false,
+        }
+    }
+}
+
+impl ImplApi {
+    fn has_handlers(&self) -> bool {
+        self.handlers && !self.methods.is_empty()
+    }
+
+    fn print_security_schemes(&self) -> proc_macro2::TokenStream {
+        let schemes = self.security_schemes.iter().map(|scheme| {
+            let name = scheme.name.clone();
+            let method_name = format_ident!("authenticate_{}", unraw(&to_snake_ident(&name)));
+            let trait_name = scheme.trait_name();
+            let scheme_stream = scheme.print_scheme();
+            let doc = print_doc(&format!("Authenticate `{}` security scheme with `A`", name));
+
+            quote! {
+                #doc
+                pub fn #method_name<A>(mut self) -> Self
+                where
+                    A: super::components::security_schemes::#trait_name,
+                {
+                    self.api = self.api.security_scheme(#name, #scheme_stream, A::authenticate);
+                    self
+                }
+            }
+        });
+
+        quote! { #(#schemes)* }
+    }
+
+    /// Helpers configuring middleware and app data of every operation and of every tag
+    fn print_groups(&self) -> proc_macro2::TokenStream {
+        let routes = self.methods.iter().map(|m| {
+            let method_name = format_ident!("route_{}", unraw(&to_snake_ident(&m.name)));
+            let operation = m.print_operation();
+            let doc = print_doc(&format!(
+                "Middleware and app data of `{} {}`",
+                m.method, m.path
+            ));
+
+            quote! {
+                #doc
+                pub fn #method_name(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(self, |api| &mut api.api, vec![#operation])
+                }
+            }
+        });
+
+        let mut tags: IndexMap<&str, Vec<&BindApiMethod>> = IndexMap::new();
+        for method in self.methods.iter() {
+            for tag in method.tags.iter() {
+                tags.entry(tag).or_default().push(method);
+            }
+        }
+
+        let tags = tags.iter().map(|(tag, methods)| {
+            let method_name = format_ident!("tag_{}", unraw(&to_snake_ident(tag)));
+            let operations = methods.iter().map(|m| m.print_operation());
+            let doc = print_doc(&format!(
+                "Middleware and app data of operations tagged `{}`",
+                tag
+            ));
+
+            quote! {
+                #doc
+                pub fn #method_name(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(self, |api| &mut api.api, vec![#(#operations),*])
+                }
+            }
+        });
+
+        quote! {
+            #(#routes)*
+            #(#tags)*
+        }
+    }
+
+    /// Trait `<Api>Handlers`, api built with `from_handlers` has every route registered
+    ///
+    /// Api without operations, main one when every operation is split by tag, has no trait
+    fn print_handlers(&self) -> proc_macro2::TokenStream {
+        if !self.has_handlers() {
+            return quote! {};
+        }
+
+        let api_name = to_type_ident(&to_struct_name(self.api_name.to_owned()));
+        let trait_name = format_ident!("{}Handlers", unraw(&api_name));
+        let signatures = self.methods.iter().map(|m| m.print_handler_signature());
+        let routes = self.methods.iter().map(|m| m.print_handler_route());
+        let trait_doc = print_doc(&format!(
+            "Handler for every operation of `{}`, use it with `{}::from_handlers`",
+            api_name, api_name
+        ));
+
+        let constructor_doc =
+            print_doc("Register every operation, routes call methods of `handlers`");
+
+        quote! {
+            #trait_doc
+            pub trait #trait_name: 'static {
+                #(#signatures)*
+            }
+
+            impl #api_name {
+                #constructor_doc
+                pub fn from_handlers<H: #trait_name>(handlers: H) -> Self {
+                    let handlers = Rc::new(handlers);
+                    let mut api = Self::new();
+
+                    #(#routes)*
+
+                    api
+                }
+            }
         }
     }
 }
 
 impl Printable for ImplApi {
     fn print(&self) -> proc_macro2::TokenStream {
-        let api_name = format_ident!("{}", to_struct_name(self.api_name.to_owned()));
+        let api_name = to_type_ident(&to_struct_name(self.api_name.to_owned()));
         let methods = self.methods.print();
+        let security_schemes = self.print_security_schemes();
+        let groups = self.print_groups();
+        let handlers = self.print_handlers();
+        let uses = match self.has_handlers() {
+            true => quote! { use std::rc::Rc; },
+            false => quote! {},
+        };
 
         quote! {
             use actix_web::FromRequest;
             use actix_swagger::{Answer, Method};
             use std::future::Future;
+            #uses
             use super::paths;
 
             impl #api_name {
+                #security_schemes
                 #methods
+                #groups
             }
+
+            #handlers
         }
     }
 }
@@ -106,16 +406,18 @@ mod tests {
         ImplApi {
             api_name: "test_api".to_owned(),
             methods,
+            security_schemes: vec![],
+            handlers: false,
         }
     }
 
     #[test]
     fn default_api() {
         assert_snapshot!(shot(ImplApi::default()), @r###"
-        use super::paths;
-        use actix_swagger::{Answer, Method};
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use super::paths;
         impl Api {}
         "###);
     }
@@ -125,13 +427,15 @@ mod tests {
         let api = ImplApi {
             api_name: "Hello".to_owned(),
             methods: vec![],
+            security_schemes: vec![],
+            handlers: false,
         };
 
         assert_snapshot!(shot(api), @r###"
-        use super::paths;
-        use actix_swagger::{Answer, Method};
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use super::paths;
         impl Hello {}
         "###);
     }
@@ -141,31 +445,37 @@ mod tests {
         let api1 = ImplApi {
             api_name: "HelloGoof".to_owned(),
             methods: vec![],
+            security_schemes: vec![],
+            handlers: false,
         };
         let api2 = ImplApi {
             api_name: "thats_my_name".to_owned(),
             methods: vec![],
+            security_schemes: vec![],
+            handlers: false,
         };
         let api3 = ImplApi {
             api_name: "RANDOMIZE_THIS_F_WOOORLD".to_owned(),
             methods: vec![],
+            security_schemes: vec![],
+            handlers: false,
         };
 
         assert_snapshot!(shot(vec![api1, api2, api3]), @r###"
-        use super::paths;
-        use actix_swagger::{Answer, Method};
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
-        impl HelloGoof {}
         use super::paths;
-        use actix_swagger::{Answer, Method};
+        impl HelloGoof {}
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
-        impl ThatsMyName {}
         use super::paths;
-        use actix_swagger::{Answer, Method};
+        impl ThatsMyName {}
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use super::paths;
         impl RandomizeThisFWooorld {}
         "###);
     }
@@ -177,25 +487,130 @@ mod tests {
             name: "hey_make_my_day".to_owned(),
             path: "/hey-make/my-day".to_owned(),
             request_body: None,
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
         };
 
         assert_snapshot!(shot(api(vec![method])), @r###"
-        use super::paths;
-        use actix_swagger::{Answer, Method};
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use super::paths;
         impl TestApi {
             pub fn bind_hey_make_my_day<F, T, R>(mut self, handler: F) -> Self
             where
-                F: Factory<T, R, Answer<'static, paths::hey_make_my_day::Response>>,
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::hey_make_my_day::Response>,
+                    Future = R,
+                >,
                 T: FromRequest + 'static,
                 R: Future<Output = Answer<'static, paths::hey_make_my_day::Response>> + 'static,
             {
-                self.api = self
-                    .api
-                    .bind("/hey-make/my-day".to_owned(), Method::POST, handler);
+                self.api = self.api.bind("/hey-make/my-day", Method::POST, handler);
                 self
             }
+            /// Middleware and app data of `POST /hey-make/my-day`
+            pub fn route_hey_make_my_day(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/hey-make/my-day", Method::POST)],
+                )
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn head_options_trace() {
+        let methods = vec![
+            (HttpMethod::Head, "session_head"),
+            (HttpMethod::Options, "session_options"),
+            (HttpMethod::Trace, "session_trace"),
+        ]
+        .into_iter()
+        .map(|(method, name)| BindApiMethod {
+            method,
+            name: name.to_owned(),
+            path: "/session".to_owned(),
+            request_body: None,
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
+        })
+        .collect();
+
+        assert_snapshot!(shot(api(methods)), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl TestApi {
+            pub fn bind_session_head<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_head::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_head::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::HEAD, handler);
+                self
+            }
+            pub fn bind_session_options<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_options::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_options::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::OPTIONS, handler);
+                self
+            }
+            pub fn bind_session_trace<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_trace::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_trace::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::TRACE, handler);
+                self
+            }
+            /// Middleware and app data of `HEAD /session`
+            pub fn route_session_head(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::HEAD)],
+                )
+            }
+            /// Middleware and app data of `OPTIONS /session`
+            pub fn route_session_options(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::OPTIONS)],
+                )
+            }
+            /// Middleware and app data of `TRACE /session`
+            pub fn route_session_trace(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::TRACE)],
+                )
+            }
         }
         "###);
     }
@@ -207,6 +622,9 @@ mod tests {
             name: "hey_make_my_day".to_owned(),
             path: "/hey-make/my-day".to_owned(),
             request_body: None,
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
         };
 
         let method2 = BindApiMethod {
@@ -214,39 +632,74 @@ mod tests {
             name: "ThisIsMyTestNameInPascalCase".to_owned(),
             path: "/Very/Very/VEry/Loo000ng/Path".to_owned(),
             request_body: None,
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
         };
 
         assert_snapshot!(shot(api(vec![method1, method2])), @r###"
-        use super::paths;
-        use actix_swagger::{Answer, Method};
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use super::paths;
         impl TestApi {
             pub fn bind_hey_make_my_day<F, T, R>(mut self, handler: F) -> Self
             where
-                F: Factory<T, R, Answer<'static, paths::hey_make_my_day::Response>>,
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::hey_make_my_day::Response>,
+                    Future = R,
+                >,
                 T: FromRequest + 'static,
                 R: Future<Output = Answer<'static, paths::hey_make_my_day::Response>> + 'static,
             {
-                self.api = self
-                    .api
-                    .bind("/hey-make/my-day".to_owned(), Method::POST, handler);
+                self.api = self.api.bind("/hey-make/my-day", Method::POST, handler);
                 self
             }
-            pub fn bind_this_is_my_test_name_in_pascal_case<F, T, R>(mut self, handler: F) -> Self
+            pub fn bind_this_is_my_test_name_in_pascal_case<F, T, R>(
+                mut self,
+                handler: F,
+            ) -> Self
             where
-                F: Factory<T, R, Answer<'static, paths::this_is_my_test_name_in_pascal_case::Response>>,
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<
+                        'static,
+                        paths::this_is_my_test_name_in_pascal_case::Response,
+                    >,
+                    Future = R,
+                >,
                 T: FromRequest + 'static,
-                R: Future<Output = Answer<'static, paths::this_is_my_test_name_in_pascal_case::Response>>
-                    + 'static,
+                R: Future<
+                        Output = Answer<
+                            'static,
+                            paths::this_is_my_test_name_in_pascal_case::Response,
+                        >,
+                    > + 'static,
             {
-                self.api = self.api.bind(
-                    "/Very/Very/VEry/Loo000ng/Path".to_owned(),
-                    Method::DELETE,
-                    handler,
-                );
+                self.api = self
+                    .api
+                    .bind("/Very/Very/VEry/Loo000ng/Path", Method::DELETE, handler);
                 self
             }
+            /// Middleware and app data of `POST /hey-make/my-day`
+            pub fn route_hey_make_my_day(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/hey-make/my-day", Method::POST)],
+                )
+            }
+            /// Middleware and app data of `DELETE /Very/Very/VEry/Loo000ng/Path`
+            pub fn route_this_is_my_test_name_in_pascal_case(
+                self,
+            ) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/Very/Very/VEry/Loo000ng/Path", Method::DELETE)],
+                )
+            }
         }
         "###);
     }
@@ -258,24 +711,368 @@ mod tests {
             name: "sessionCreate".to_owned(),
             path: "/session".to_owned(),
             request_body: Some("SessionCreateBody".to_owned()),
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
         };
 
         assert_snapshot!(shot(api(vec![method])), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
         use super::paths;
+        impl TestApi {
+            /// Request body - super::requst_bodies::SessionCreateBody
+            pub fn bind_session_create<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_create::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_create::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::POST, handler);
+                self
+            }
+            /// Middleware and app data of `POST /session`
+            pub fn route_session_create(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::POST)],
+                )
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn with_security() {
+        use crate::printer::components::security_schemes::{
+            ApiKeyLocation, SecurityScheme, SecuritySchemeKind,
+        };
+
+        let method = BindApiMethod {
+            method: HttpMethod::Get,
+            name: "sessionGet".to_owned(),
+            path: "/session".to_owned(),
+            request_body: None,
+            security: vec![
+                SecurityRequirement {
+                    schemes: vec![("bearerAuth".to_owned(), vec!["session:read".to_owned()])],
+                },
+                SecurityRequirement {
+                    schemes: vec![
+                        ("bearerAuth".to_owned(), vec![]),
+                        ("apiKey".to_owned(), vec![]),
+                    ],
+                },
+            ],
+            tags: vec![],
+            extractors: vec![],
+        };
+
+        let api = ImplApi {
+            api_name: "test_api".to_owned(),
+            methods: vec![method],
+            security_schemes: vec![
+                SecurityScheme {
+                    name: "bearerAuth".to_owned(),
+                    description: None,
+                    kind: SecuritySchemeKind::Bearer,
+                },
+                SecurityScheme {
+                    name: "apiKey".to_owned(),
+                    description: None,
+                    kind: SecuritySchemeKind::ApiKey {
+                        location: ApiKeyLocation::Header,
+                        name: "X-Api-Key".to_owned(),
+                    },
+                },
+            ],
+            handlers: false,
+        };
+
+        assert_snapshot!(shot(api), @r###"
+        use actix_web::FromRequest;
         use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl TestApi {
+            /// Authenticate `bearerAuth` security scheme with `A`
+            pub fn authenticate_bearer_auth<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::BearerAuth,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "bearerAuth",
+                        actix_swagger::security::Scheme::Bearer,
+                        A::authenticate,
+                    );
+                self
+            }
+            /// Authenticate `apiKey` security scheme with `A`
+            pub fn authenticate_api_key<A>(mut self) -> Self
+            where
+                A: super::components::security_schemes::ApiKey,
+            {
+                self.api = self
+                    .api
+                    .security_scheme(
+                        "apiKey",
+                        actix_swagger::security::Scheme::ApiKey {
+                            location: actix_swagger::security::ApiKeyLocation::Header,
+                            name: "X-Api-Key",
+                        },
+                        A::authenticate,
+                    );
+                self
+            }
+            pub fn bind_session_get<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_get::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_get::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::GET, handler);
+                self.api = self
+                    .api
+                    .security(
+                        "/session",
+                        Method::GET,
+                        &[
+                            &[("bearerAuth", &["session:read"])],
+                            &[("bearerAuth", &[]), ("apiKey", &[])],
+                        ],
+                    );
+                self
+            }
+            /// Middleware and app data of `GET /session`
+            pub fn route_session_get(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::GET)],
+                )
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn handlers_with_parameters() {
+        let get = BindApiMethod {
+            method: HttpMethod::Get,
+            name: "userGet".to_owned(),
+            path: "/users/{id}".to_owned(),
+            request_body: None,
+            security: vec![],
+            tags: vec![],
+            extractors: vec![Extractor::Path, Extractor::Query],
+        };
+
+        let api = ImplApi {
+            api_name: "test_api".to_owned(),
+            methods: vec![get],
+            security_schemes: vec![],
+            handlers: true,
+        };
+        let code = shot(api);
+        let handlers = &code[code.find("pub trait").unwrap()..];
+
+        assert_snapshot!(handlers, @r###"
+        pub trait TestApiHandlers: 'static {
+            /// GET /users/{id}
+            fn user_get(
+                &self,
+                request: actix_web::HttpRequest,
+                path: paths::user_get::Path,
+                query: paths::user_get::Query,
+            ) -> impl Future<Output = Answer<'static, paths::user_get::Response>>;
+        }
+        impl TestApi {
+            /// Register every operation, routes call methods of `handlers`
+            pub fn from_handlers<H: TestApiHandlers>(handlers: H) -> Self {
+                let handlers = Rc::new(handlers);
+                let mut api = Self::new();
+                let handler = Rc::clone(&handlers);
+                api.api = api
+                    .api
+                    .bind(
+                        "/users/{id}",
+                        Method::GET,
+                        move |
+                            request: actix_web::HttpRequest,
+                            path: paths::user_get::Path,
+                            query: paths::user_get::Query|
+                        {
+                            let handlers = Rc::clone(&handler);
+                            async move { handlers.user_get(request, path, query).await }
+                        },
+                    );
+                api
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn no_handlers_trait_without_operations() {
+        let api = ImplApi {
+            api_name: "test_api".to_owned(),
+            methods: vec![],
+            security_schemes: vec![],
+            handlers: true,
+        };
+
+        assert_snapshot!(shot(api), @r###"
+        use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
+        use std::future::Future;
+        use super::paths;
+        impl TestApi {}
+        "###);
+    }
+
+    #[test]
+    fn handlers_trait() {
+        let create = BindApiMethod {
+            method: HttpMethod::Post,
+            name: "sessionCreate".to_owned(),
+            path: "/session".to_owned(),
+            request_body: Some("SessionCreateBody".to_owned()),
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
+        };
+        let get = BindApiMethod {
+            method: HttpMethod::Get,
+            name: "sessionGet".to_owned(),
+            path: "/session".to_owned(),
+            request_body: None,
+            security: vec![SecurityRequirement {
+                schemes: vec![("bearerAuth".to_owned(), vec![])],
+            }],
+            tags: vec![],
+            extractors: vec![],
+        };
+
+        let api = ImplApi {
+            api_name: "test_api".to_owned(),
+            methods: vec![create, get],
+            security_schemes: vec![],
+            handlers: true,
+        };
+
+        assert_snapshot!(shot(api), @r###"
         use actix_web::FromRequest;
+        use actix_swagger::{Answer, Method};
         use std::future::Future;
+        use std::rc::Rc;
+        use super::paths;
         impl TestApi {
-            #[doc = "Request body - super::requst_bodies::SessionCreateBody"]
+            /// Request body - super::requst_bodies::SessionCreateBody
             pub fn bind_session_create<F, T, R>(mut self, handler: F) -> Self
             where
-                F: Factory<T, R, Answer<'static, paths::session_create::Response>>,
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_create::Response>,
+                    Future = R,
+                >,
                 T: FromRequest + 'static,
                 R: Future<Output = Answer<'static, paths::session_create::Response>> + 'static,
             {
-                self.api = self.api.bind("/session".to_owned(), Method::POST, handler);
+                self.api = self.api.bind("/session", Method::POST, handler);
+                self
+            }
+            pub fn bind_session_get<F, T, R>(mut self, handler: F) -> Self
+            where
+                F: actix_web::Handler<
+                    T,
+                    Output = Answer<'static, paths::session_get::Response>,
+                    Future = R,
+                >,
+                T: FromRequest + 'static,
+                R: Future<Output = Answer<'static, paths::session_get::Response>> + 'static,
+            {
+                self.api = self.api.bind("/session", Method::GET, handler);
+                self.api = self.api.security("/session", Method::GET, &[&[("bearerAuth", &[])]]);
                 self
             }
+            /// Middleware and app data of `POST /session`
+            pub fn route_session_create(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::POST)],
+                )
+            }
+            /// Middleware and app data of `GET /session`
+            pub fn route_session_get(self) -> actix_swagger::Group<Self> {
+                actix_swagger::Group::new(
+                    self,
+                    |api| &mut api.api,
+                    vec![("/session", Method::GET)],
+                )
+            }
+        }
+        /// Handler for every operation of `TestApi`, use it with `TestApi::from_handlers`
+        pub trait TestApiHandlers: 'static {
+            /// POST /session
+            fn session_create(
+                &self,
+                request: actix_web::HttpRequest,
+                body: actix_web::web::Json<super::components::request_bodies::SessionCreateBody>,
+            ) -> impl Future<Output = Answer<'static, paths::session_create::Response>>;
+            /// GET /session
+            fn session_get(
+                &self,
+                request: actix_web::HttpRequest,
+            ) -> impl Future<Output = Answer<'static, paths::session_get::Response>>;
+        }
+        impl TestApi {
+            /// Register every operation, routes call methods of `handlers`
+            pub fn from_handlers<H: TestApiHandlers>(handlers: H) -> Self {
+                let handlers = Rc::new(handlers);
+                let mut api = Self::new();
+                let handler = Rc::clone(&handlers);
+                api.api = api
+                    .api
+                    .bind(
+                        "/session",
+                        Method::POST,
+                        move |
+                            request: actix_web::HttpRequest,
+                            body: actix_web::web::Json<
+                                super::components::request_bodies::SessionCreateBody,
+                            >|
+                        {
+                            let handlers = Rc::clone(&handler);
+                            async move { handlers.session_create(request, body).await }
+                        },
+                    );
+                let handler = Rc::clone(&handlers);
+                api.api = api
+                    .api
+                    .bind(
+                        "/session",
+                        Method::GET,
+                        move |request: actix_web::HttpRequest| {
+                            let handlers = Rc::clone(&handler);
+                            async move { handlers.session_get(request).await }
+                        },
+                    );
+                api.api = api.api.security("/session", Method::GET, &[&[("bearerAuth", &[])]]);
+                api
+            }
         }
         "###);
     }
@@ -1,12 +1,34 @@
 use super::methods::ImplApi;
-use super::structure::ApiStruct;
-use crate::printer::Printable;
+use super::structure::{to_struct_name, ApiStruct};
+use crate::printer::{
+    ident::{to_snake_ident, unraw},
+    Printable,
+};
+use indexmap::IndexMap;
 use quote::quote;
 
+/// Names imported into `paths` and `api` modules, tag modules cannot take them
+const RESERVED_MODULES: &[&str] = &["components", "parameters", "paths", "responses"];
+
+/// Operations of the tag with own module in `paths` and `api`
+#[derive(Debug, PartialEq)]
+pub struct TagGroup {
+    pub tag: String,
+
+    /// Name of the module, suffixed with `_tag` when an operation or another tag has the same name
+    pub module: String,
+
+    /// Names of the operations
+    pub names: Vec<String>,
+}
+
 #[derive(Default)]
 pub struct ApiModule {
     pub structure: ApiStruct,
     pub methods: ImplApi,
+
+    /// Put operations of every tag into own module with api that can be mounted on its own
+    pub split_by_tag: bool,
 }
 
 impl ApiModule {
@@ -22,17 +44,220 @@ impl ApiModule {
     pub fn set_terms_of_service(&mut self, terms: Option<String>) {
         self.structure.terms_of_service = terms;
     }
+
+    pub fn set_spec(&mut self, spec: Option<String>) {
+        self.structure.spec = spec;
+    }
+
+    pub fn set_prefix(&mut self, prefix: String) {
+        self.structure.prefix = prefix;
+    }
+
+    /// Operations of every tag put into own module, empty when code is not split by tag
+    ///
+    /// Each path is served by one api, so it answers `405` and `OPTIONS` correctly:
+    /// path goes to the module of a tag when first tag of every its operation is that tag,
+    /// otherwise it stays in the main api. Paths of different modules that can match the same request
+    /// stay in the main api too, actix matches them in order of registration which is unknown across apis.
+    pub fn tag_groups(&self) -> Vec<TagGroup> {
+        if !self.split_by_tag {
+            return vec![];
+        }
+
+        let methods = &self.methods.methods;
+
+        // Tag of every path, `None` keeps path in the main api
+        let mut path_tags: IndexMap<&str, Option<&str>> = IndexMap::new();
+        for method in methods.iter() {
+            let tag = method.tags.first().map(String::as_str);
+            path_tags
+                .entry(&method.path)
+                .and_modify(|current| {
+                    if *current != tag {
+                        *current = None;
+                    }
+                })
+                .or_insert(tag);
+        }
+
+        loop {
+            let mut conflicts = vec![];
+
+            for (index, (first, first_tag)) in path_tags.iter().enumerate() {
+                for (second, second_tag) in path_tags.iter().skip(index + 1) {
+                    if first_tag != second_tag && overlap(first, second) {
+                        conflicts.push((*first, *second));
+                    }
+                }
+            }
+
+            if conflicts.is_empty() {
+                break;
+            }
+
+            for (first, second) in conflicts {
+                log::warn!(
+                    "{} and {} can match the same request, both are kept in the main api",
+                    first,
+                    second
+                );
+                path_tags.insert(first, None);
+                path_tags.insert(second, None);
+            }
+        }
+
+        let mut groups: IndexMap<&str, Vec<String>> = IndexMap::new();
+        for method in methods.iter() {
+            if let Some(Some(tag)) = path_tags.get(method.path.as_str()) {
+                groups.entry(tag).or_default().push(method.name.clone());
+            }
+        }
+
+        // Module of the tag lives next to modules of operations in `paths`
+        let mut taken: Vec<String> = RESERVED_MODULES
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        taken.extend(
+            methods
+                .iter()
+                .map(|method| unraw(&to_snake_ident(&method.name))),
+        );
+
+        groups
+            .into_iter()
+            .map(|(tag, names)| {
+                let base = unraw(&to_snake_ident(tag));
+                let mut module = base.clone();
+                let mut index = 1;
+
+                while taken.contains(&module) {
+                    module = match index {
+                        1 => format!("{}_tag", base),
+                        _ => format!("{}_tag{}", base, index),
+                    };
+                    index += 1;
+                }
+
+                taken.push(module.clone());
+
+                TagGroup {
+                    tag: tag.to_owned(),
+                    module,
+                    names,
+                }
+            })
+            .collect()
+    }
+
+    /// Api struct and methods with operations named in `names` only
+    fn slice(&self, api_name: String, names: &[&String]) -> (ApiStruct, ImplApi) {
+        let methods: Vec<_> = self
+            .methods
+            .methods
+            .iter()
+            .filter(|method| names.contains(&&method.name))
+            .cloned()
+            .collect();
+
+        let structure = ApiStruct {
+            api_name: api_name.clone(),
+            operations: self
+                .structure
+                .operations
+                .iter()
+                .filter(|(path, _)| methods.iter().any(|method| &method.path == path))
+                .cloned()
+                .collect(),
+            ..self.structure.clone()
+        };
+
+        let methods = ImplApi {
+            api_name,
+            methods,
+            security_schemes: self.methods.security_schemes.clone(),
+            handlers: self.methods.handlers,
+        };
+
+        (structure, methods)
+    }
+}
+
+/// Whether templates of paths can match the same request, `/users/me` and `/users/{id}` for example
+fn overlap(first: &str, second: &str) -> bool {
+    let first: Vec<&str> = first.split('/').collect();
+    let second: Vec<&str> = second.split('/').collect();
+
+    first.len() == second.len()
+        && first
+            .iter()
+            .zip(second.iter())
+            .all(|(left, right)| left == right || left.contains('{') || right.contains('{'))
 }
 
 impl Printable for ApiModule {
     fn print(&self) -> proc_macro2::TokenStream {
-        let api_struct = self.structure.print();
-        let methods_impl = self.methods.print();
+        let groups = self.tag_groups();
+
+        if groups.is_empty() {
+            let api_struct = self.structure.print();
+            let methods_impl = self.methods.print();
+
+            return quote! {
+                pub mod api {
+                    #api_struct
+
+                    #methods_impl
+                }
+            };
+        }
+
+        let grouped: Vec<&String> = groups.iter().flat_map(|group| &group.names).collect();
+        let untagged: Vec<&String> = self
+            .methods
+            .methods
+            .iter()
+            .map(|method| &method.name)
+            .filter(|name| !grouped.contains(name))
+            .collect();
+
+        let (api_struct, methods_impl) = self.slice(self.structure.api_name.clone(), &untagged);
+        let api_struct = api_struct.print();
+        let methods_impl = methods_impl.print();
+
+        let modules = groups.iter().map(|group| {
+            let TagGroup { tag, module, names } = group;
+            let module_name = to_snake_ident(module);
+            let api_name = to_struct_name(format!("{} api", tag));
+            let names: Vec<&String> = names.iter().collect();
+            let (mut api_struct, methods_impl) = self.slice(api_name, &names);
+
+            // Specification and documentation are served by the main api
+            api_struct.description = Some(format!("Operations tagged `{}`", tag));
+            api_struct.terms_of_service = None;
+            api_struct.spec = None;
+
+            let api_struct = api_struct.print();
+            let methods_impl = methods_impl.print();
+
+            quote! {
+                pub mod #module_name {
+                    #api_struct
+
+                    #methods_impl
+                }
+            }
+        });
+
         quote! {
             pub mod api {
+                use super::components;
+
                 #api_struct
 
                 #methods_impl
+
+                #(#modules)*
             }
         }
     }
@@ -41,6 +266,7 @@ impl Printable for ApiModule {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::printer::api::{BindApiMethod, HttpMethod};
     use crate::test::shot;
     use insta::assert_snapshot;
 
@@ -57,11 +283,24 @@ mod tests {
                         api: actix_swagger::Api::new(),
                     }
                 }
+                /// Mount routes under `prefix` instead of path of the server
+                pub fn with_prefix(mut self, prefix: &str) -> Self {
+                    self.api = self.api.with_prefix(prefix);
+                    self
+                }
+                /// Panic on register when some operation has no handler, otherwise it answers 501
+                pub fn strict(mut self, strict: bool) -> Self {
+                    self.api = self.api.strict(strict);
+                    self
+                }
+                /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+                pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                    self.api.check()
+                }
             }
             impl Default for Api {
                 fn default() -> Self {
-                    let api = Self::new();
-                    api
+                    Self::new()
                 }
             }
             impl actix_web::dev::HttpServiceFactory for Api {
@@ -69,12 +308,306 @@ mod tests {
                     self.api.register(config);
                 }
             }
-            use super::paths;
-            use actix_swagger::{Answer, Method};
             use actix_web::FromRequest;
+            use actix_swagger::{Answer, Method};
             use std::future::Future;
+            use super::paths;
             impl Api {}
         }
         "###);
     }
+
+    #[test]
+    fn split_by_tag() {
+        let method = |method, name: &str, path: &str, tags: &[&str]| BindApiMethod {
+            method,
+            name: name.to_owned(),
+            path: path.to_owned(),
+            request_body: None,
+            security: vec![],
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            extractors: vec![],
+        };
+
+        let mut module = ApiModule {
+            split_by_tag: true,
+            ..ApiModule::default()
+        };
+        module.set_name("test_api".to_owned());
+        module.structure.operations = vec![
+            ("/session".to_owned(), HttpMethod::Get),
+            ("/session".to_owned(), HttpMethod::Post),
+            ("/oauth/authorize".to_owned(), HttpMethod::Post),
+            ("/health".to_owned(), HttpMethod::Get),
+        ];
+        module.methods.methods = vec![
+            method(HttpMethod::Get, "session_get", "/session", &["Session"]),
+            method(HttpMethod::Post, "session_create", "/session", &["OAuth"]),
+            method(
+                HttpMethod::Post,
+                "oauth_authorize",
+                "/oauth/authorize",
+                &["OAuth"],
+            ),
+            method(HttpMethod::Get, "health", "/health", &[]),
+        ];
+
+        // `/session` has operations of different tags, so it stays in the main api
+        assert_eq!(
+            module.tag_groups(),
+            vec![TagGroup {
+                tag: "OAuth".to_owned(),
+                module: "oauth".to_owned(),
+                names: vec!["oauth_authorize".to_owned()],
+            }]
+        );
+        assert_snapshot!(shot(module), @r###"
+        pub mod api {
+            use super::components;
+            pub struct TestApi {
+                api: actix_swagger::Api,
+            }
+            impl TestApi {
+                pub fn new() -> Self {
+                    Self {
+                        api: actix_swagger::Api::new()
+                            .operation("/session", actix_swagger::Method::GET)
+                            .operation("/session", actix_swagger::Method::POST)
+                            .operation("/health", actix_swagger::Method::GET),
+                    }
+                }
+                /// Mount routes under `prefix` instead of path of the server
+                pub fn with_prefix(mut self, prefix: &str) -> Self {
+                    self.api = self.api.with_prefix(prefix);
+                    self
+                }
+                /// Panic on register when some operation has no handler, otherwise it answers 501
+                pub fn strict(mut self, strict: bool) -> Self {
+                    self.api = self.api.strict(strict);
+                    self
+                }
+                /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+                pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                    self.api.check()
+                }
+            }
+            impl Default for TestApi {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+            impl actix_web::dev::HttpServiceFactory for TestApi {
+                fn register(self, config: &mut actix_web::dev::AppService) {
+                    self.api.register(config);
+                }
+            }
+            use actix_web::FromRequest;
+            use actix_swagger::{Answer, Method};
+            use std::future::Future;
+            use super::paths;
+            impl TestApi {
+                pub fn bind_session_get<F, T, R>(mut self, handler: F) -> Self
+                where
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::session_get::Response>,
+                        Future = R,
+                    >,
+                    T: FromRequest + 'static,
+                    R: Future<Output = Answer<'static, paths::session_get::Response>> + 'static,
+                {
+                    self.api = self.api.bind("/session", Method::GET, handler);
+                    self
+                }
+                pub fn bind_session_create<F, T, R>(mut self, handler: F) -> Self
+                where
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::session_create::Response>,
+                        Future = R,
+                    >,
+                    T: FromRequest + 'static,
+                    R: Future<Output = Answer<'static, paths::session_create::Response>>
+                        + 'static,
+                {
+                    self.api = self.api.bind("/session", Method::POST, handler);
+                    self
+                }
+                pub fn bind_health<F, T, R>(mut self, handler: F) -> Self
+                where
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::health::Response>,
+                        Future = R,
+                    >,
+                    T: FromRequest + 'static,
+                    R: Future<Output = Answer<'static, paths::health::Response>> + 'static,
+                {
+                    self.api = self.api.bind("/health", Method::GET, handler);
+                    self
+                }
+                /// Middleware and app data of `GET /session`
+                pub fn route_session_get(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::GET)],
+                    )
+                }
+                /// Middleware and app data of `POST /session`
+                pub fn route_session_create(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::POST)],
+                    )
+                }
+                /// Middleware and app data of `GET /health`
+                pub fn route_health(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/health", Method::GET)],
+                    )
+                }
+                /// Middleware and app data of operations tagged `Session`
+                pub fn tag_session(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::GET)],
+                    )
+                }
+                /// Middleware and app data of operations tagged `OAuth`
+                pub fn tag_oauth(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::POST)],
+                    )
+                }
+            }
+            pub mod oauth {
+                /// Operations tagged `OAuth`
+                pub struct OauthApi {
+                    api: actix_swagger::Api,
+                }
+                impl OauthApi {
+                    pub fn new() -> Self {
+                        Self {
+                            api: actix_swagger::Api::new()
+                                .operation("/oauth/authorize", actix_swagger::Method::POST),
+                        }
+                    }
+                    /// Mount routes under `prefix` instead of path of the server
+                    pub fn with_prefix(mut self, prefix: &str) -> Self {
+                        self.api = self.api.with_prefix(prefix);
+                        self
+                    }
+                    /// Panic on register when some operation has no handler, otherwise it answers 501
+                    pub fn strict(mut self, strict: bool) -> Self {
+                        self.api = self.api.strict(strict);
+                        self
+                    }
+                    /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+                    pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                        self.api.check()
+                    }
+                }
+                impl Default for OauthApi {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+                impl actix_web::dev::HttpServiceFactory for OauthApi {
+                    fn register(self, config: &mut actix_web::dev::AppService) {
+                        self.api.register(config);
+                    }
+                }
+                use actix_web::FromRequest;
+                use actix_swagger::{Answer, Method};
+                use std::future::Future;
+                use super::paths;
+                impl OauthApi {
+                    pub fn bind_oauth_authorize<F, T, R>(mut self, handler: F) -> Self
+                    where
+                        F: actix_web::Handler<
+                            T,
+                            Output = Answer<'static, paths::oauth_authorize::Response>,
+                            Future = R,
+                        >,
+                        T: FromRequest + 'static,
+                        R: Future<Output = Answer<'static, paths::oauth_authorize::Response>>
+                            + 'static,
+                    {
+                        self.api = self.api.bind("/oauth/authorize", Method::POST, handler);
+                        self
+                    }
+                    /// Middleware and app data of `POST /oauth/authorize`
+                    pub fn route_oauth_authorize(self) -> actix_swagger::Group<Self> {
+                        actix_swagger::Group::new(
+                            self,
+                            |api| &mut api.api,
+                            vec![("/oauth/authorize", Method::POST)],
+                        )
+                    }
+                    /// Middleware and app data of operations tagged `OAuth`
+                    pub fn tag_oauth(self) -> actix_swagger::Group<Self> {
+                        actix_swagger::Group::new(
+                            self,
+                            |api| &mut api.api,
+                            vec![("/oauth/authorize", Method::POST)],
+                        )
+                    }
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn tag_groups_keep_overlapping_paths_and_suffix_clashing_modules() {
+        let method = |name: &str, path: &str, tag: &str| BindApiMethod {
+            method: HttpMethod::Get,
+            name: name.to_owned(),
+            path: path.to_owned(),
+            request_body: None,
+            security: vec![],
+            tags: vec![tag.to_owned()],
+            extractors: vec![],
+        };
+
+        let mut module = ApiModule {
+            split_by_tag: true,
+            ..ApiModule::default()
+        };
+        module.methods.methods = vec![
+            method("users_me", "/users/me", "Me"),
+            method("users_get", "/users/{id}", "Users"),
+            method("users_list", "/users", "Users"),
+            method("audit", "/audit", "Audit"),
+            method("responses_get", "/responses", "Responses"),
+        ];
+
+        assert_eq!(
+            module.tag_groups(),
+            vec![
+                TagGroup {
+                    tag: "Users".to_owned(),
+                    module: "users".to_owned(),
+                    names: vec!["users_list".to_owned()],
+                },
+                TagGroup {
+                    tag: "Audit".to_owned(),
+                    module: "audit_tag".to_owned(),
+                    names: vec!["audit".to_owned()],
+                },
+                TagGroup {
+                    tag: "Responses".to_owned(),
+                    module: "responses_tag".to_owned(),
+                    names: vec!["responses_get".to_owned()],
+                },
+            ]
+        );
+    }
 }
@@ -1,4 +1,5 @@
-use crate::printer::Printable;
+use super::methods::HttpMethod;
+use crate::printer::{ident::to_type_ident, print_doc, Printable};
 use inflections::Inflect;
 use quote::{format_ident, quote};
 use regex::Regex;
@@ -13,10 +14,20 @@ pub fn to_struct_name(string: String) -> String {
 }
 
 /// Object describing main api structure and useful impls
+#[derive(Clone)]
 pub struct ApiStruct {
     pub api_name: String,
     pub terms_of_service: Option<String>,
     pub description: Option<String>,
+
+    /// Every operation of the specification, path and method
+    pub operations: Vec<(String, HttpMethod)>,
+
+    /// Source specification in JSON, served with `openapi`
+    pub spec: Option<String>,
+
+    /// Path of the server, empty when routes are mounted at root
+    pub prefix: String,
     // pub license: Option<String>,
     // pub contact: Option<String>,
     // pub version: semver::Version,
@@ -28,13 +39,16 @@ impl Default for ApiStruct {
             api_name: "Api".to_owned(),
             terms_of_service: None,
             description: None,
+            operations: vec![],
+            spec: None,
+            prefix: String::new(),
         }
     }
 }
 
 impl Printable for ApiStruct {
     fn print(&self) -> proc_macro2::TokenStream {
-        let api_name = format_ident!("{}", to_struct_name(self.api_name.to_owned()));
+        let api_name = to_type_ident(&to_struct_name(self.api_name.to_owned()));
         let terms = self
             .terms_of_service
             .to_owned()
@@ -44,11 +58,56 @@ impl Printable for ApiStruct {
         let doc_comment = format!("{}\n{}", description, terms);
         let doc = doc_comment.trim();
 
-        let doc_stream = match doc.len() > 0 {
-            true => quote! { #[doc = #doc] },
+        let doc_stream = match !doc.is_empty() {
+            true => print_doc(doc),
             false => quote! {},
         };
 
+        let operations = self.operations.iter().map(|(path, method)| {
+            let method = format_ident!("{}", method.to_string());
+            quote! { .operation(#path, actix_swagger::Method::#method) }
+        });
+        let prefix = match self.prefix.is_empty() {
+            true => quote! {},
+            false => {
+                let prefix = &self.prefix;
+                quote! { .with_prefix(#prefix) }
+            }
+        };
+        let with_prefix_doc =
+            print_doc("Mount routes under `prefix` instead of path of the server");
+        let strict_doc = print_doc(
+            "Panic on register when some operation has no handler, otherwise it answers 501",
+        );
+        let check_doc = print_doc(
+            "Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them",
+        );
+        let spec = self.spec.as_ref().map_or_else(
+            || quote! {},
+            |spec| {
+                let const_doc = print_doc("Specification the api is generated from, in JSON");
+                let openapi_doc = print_doc("Serve specification at `path`, `/openapi.json` for example");
+                let docs_doc = print_doc("Serve documentation page at `path`, specification must be served with `openapi`");
+
+                quote! {
+                    #const_doc
+                    pub const OPENAPI: &'static str = #spec;
+
+                    #openapi_doc
+                    pub fn openapi(mut self, path: &str) -> Self {
+                        self.api = self.api.openapi(path, Self::OPENAPI);
+                        self
+                    }
+
+                    #docs_doc
+                    pub fn docs(mut self, path: &str, docs: actix_swagger::Docs) -> Self {
+                        self.api = self.api.docs(path, docs);
+                        self
+                    }
+                }
+            },
+        );
+
         quote! {
             #doc_stream
             pub struct #api_name {
@@ -58,15 +117,33 @@ impl Printable for ApiStruct {
             impl #api_name {
                 pub fn new() -> Self {
                     Self {
-                        api: actix_swagger::Api::new()
+                        api: actix_swagger::Api::new() #prefix #(#operations)*
                     }
                 }
+
+                #with_prefix_doc
+                pub fn with_prefix(mut self, prefix: &str) -> Self {
+                    self.api = self.api.with_prefix(prefix);
+                    self
+                }
+
+                #strict_doc
+                pub fn strict(mut self, strict: bool) -> Self {
+                    self.api = self.api.strict(strict);
+                    self
+                }
+
+                #check_doc
+                pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                    self.api.check()
+                }
+
+                #spec
             }
 
             impl Default for #api_name {
                 fn default() -> Self {
-                    let api = Self::new();
-                    api
+                    Self::new()
                 }
             }
 
@@ -97,11 +174,24 @@ mod tests {
                     api: actix_swagger::Api::new(),
                 }
             }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
         }
         impl Default for Api {
             fn default() -> Self {
-                let api = Self::new();
-                api
+                Self::new()
             }
         }
         impl actix_web::dev::HttpServiceFactory for Api {
@@ -117,9 +207,12 @@ mod tests {
         assert_snapshot!(shot(ApiStruct {
             api_name: "test_api".to_owned(),
             description: None,
-            terms_of_service: Some("https://example.com/terms".to_owned())
+            terms_of_service: Some("https://example.com/terms".to_owned()),
+            operations: vec![],
+            spec: None,
+            prefix: String::new(),
         }), @r###"
-        #[doc = "@see https://example.com/terms"]
+        /// @see https://example.com/terms
         pub struct TestApi {
             api: actix_swagger::Api,
         }
@@ -129,11 +222,24 @@ mod tests {
                     api: actix_swagger::Api::new(),
                 }
             }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
         }
         impl Default for TestApi {
             fn default() -> Self {
-                let api = Self::new();
-                api
+                Self::new()
             }
         }
         impl actix_web::dev::HttpServiceFactory for TestApi {
@@ -150,8 +256,12 @@ mod tests {
             api_name: "test_api".to_owned(),
             description: Some("My super simple description.\nAnother back".to_owned()),
             terms_of_service: None,
+            operations: vec![],
+            spec: None,
+            prefix: String::new(),
         }), @r###"
-        #[doc = "My super simple description.\nAnother back"]
+        /// My super simple description.
+        /// Another back
         pub struct TestApi {
             api: actix_swagger::Api,
         }
@@ -161,11 +271,24 @@ mod tests {
                     api: actix_swagger::Api::new(),
                 }
             }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
         }
         impl Default for TestApi {
             fn default() -> Self {
-                let api = Self::new();
-                api
+                Self::new()
             }
         }
         impl actix_web::dev::HttpServiceFactory for TestApi {
@@ -182,8 +305,13 @@ mod tests {
             api_name: "test_api".to_owned(),
             description: Some("My super simple description.\nAnother back".to_owned()),
             terms_of_service: Some("https://example.com/terms".to_owned()),
+            operations: vec![],
+            spec: None,
+            prefix: String::new(),
         }), @r###"
-        #[doc = "My super simple description.\nAnother back\n@see https://example.com/terms"]
+        /// My super simple description.
+        /// Another back
+        /// @see https://example.com/terms
         pub struct TestApi {
             api: actix_swagger::Api,
         }
@@ -193,11 +321,132 @@ mod tests {
                     api: actix_swagger::Api::new(),
                 }
             }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
+        }
+        impl Default for TestApi {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+        impl actix_web::dev::HttpServiceFactory for TestApi {
+            fn register(self, config: &mut actix_web::dev::AppService) {
+                self.api.register(config);
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn with_prefix() {
+        assert_snapshot!(shot(ApiStruct {
+            api_name: "test_api".to_owned(),
+            description: None,
+            terms_of_service: None,
+            operations: vec![("/users".to_owned(), HttpMethod::Get)],
+            spec: None,
+            prefix: "/api/v2".to_owned(),
+        }), @r###"
+        pub struct TestApi {
+            api: actix_swagger::Api,
+        }
+        impl TestApi {
+            pub fn new() -> Self {
+                Self {
+                    api: actix_swagger::Api::new()
+                        .with_prefix("/api/v2")
+                        .operation("/users", actix_swagger::Method::GET),
+                }
+            }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
+        }
+        impl Default for TestApi {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+        impl actix_web::dev::HttpServiceFactory for TestApi {
+            fn register(self, config: &mut actix_web::dev::AppService) {
+                self.api.register(config);
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn with_spec() {
+        assert_snapshot!(shot(ApiStruct {
+            api_name: "test_api".to_owned(),
+            description: None,
+            terms_of_service: None,
+            operations: vec![],
+            spec: Some(r#"{"openapi":"3.0.1"}"#.to_owned()),
+            prefix: String::new(),
+        }), @r###"
+        pub struct TestApi {
+            api: actix_swagger::Api,
+        }
+        impl TestApi {
+            pub fn new() -> Self {
+                Self {
+                    api: actix_swagger::Api::new(),
+                }
+            }
+            /// Mount routes under `prefix` instead of path of the server
+            pub fn with_prefix(mut self, prefix: &str) -> Self {
+                self.api = self.api.with_prefix(prefix);
+                self
+            }
+            /// Panic on register when some operation has no handler, otherwise it answers 501
+            pub fn strict(mut self, strict: bool) -> Self {
+                self.api = self.api.strict(strict);
+                self
+            }
+            /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+            pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                self.api.check()
+            }
+            /// Specification the api is generated from, in JSON
+            pub const OPENAPI: &'static str = "{\"openapi\":\"3.0.1\"}";
+            /// Serve specification at `path`, `/openapi.json` for example
+            pub fn openapi(mut self, path: &str) -> Self {
+                self.api = self.api.openapi(path, Self::OPENAPI);
+                self
+            }
+            /// Serve documentation page at `path`, specification must be served with `openapi`
+            pub fn docs(mut self, path: &str, docs: actix_swagger::Docs) -> Self {
+                self.api = self.api.docs(path, docs);
+                self
+            }
         }
         impl Default for TestApi {
             fn default() -> Self {
-                let api = Self::new();
-                api
+                Self::new()
             }
         }
         impl actix_web::dev::HttpServiceFactory for TestApi {
@@ -0,0 +1,304 @@
+use crate::printer::{
+    api::HttpMethod,
+    ident::{to_pascal_ident, to_snake_ident},
+    paths::{HeaderParam, PathParam, QueryParam},
+    print_doc, Printable,
+};
+use quote::{format_ident, quote};
+use regex::Regex;
+
+/// Operation of the client, sends request and decodes `paths::<name>::Response`
+pub struct ClientMethod {
+    pub method: HttpMethod,
+    pub path: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub request_body: Option<String>,
+    pub path_params: Vec<PathParam>,
+    pub header_params: Vec<HeaderParam>,
+
+    /// Operation has `paths::<name>::QueryParams`
+    pub has_query: bool,
+
+    /// Operation has `paths::<name>::CookieParams`
+    pub has_cookies: bool,
+}
+
+impl ClientMethod {
+    fn param_ident(param: &QueryParam) -> proc_macro2::Ident {
+        to_snake_ident(&param.name)
+    }
+
+    fn param_type(param: &QueryParam) -> proc_macro2::TokenStream {
+        let type_name = to_pascal_ident(&param.type_ref);
+
+        match param.required {
+            true => quote! { &parameters::#type_name },
+            false => quote! { Option<&parameters::#type_name> },
+        }
+    }
+
+    /// Format string of the url, every path param in `{name}` is passed in order of the path
+    fn print_url(&self) -> proc_macro2::TokenStream {
+        let re_param = Regex::new(r"\{([^}]+)\}").expect("re_param invalid regex");
+        let template = format!("{{}}{}", re_param.replace_all(&self.path, "{}"));
+        let segments = re_param.captures_iter(&self.path).map(|captures| {
+            let ident = to_snake_ident(&captures[1]);
+
+            quote! { actix_swagger::client::path_segment(#ident)? }
+        });
+
+        quote! { format!(#template, self.base_url #(, #segments)*) }
+    }
+
+    fn print_headers(&self) -> proc_macro2::TokenStream {
+        let headers = self.header_params.iter().map(|param| {
+            let name = param.name.clone();
+            let ident = Self::param_ident(param);
+
+            match param.required {
+                true => quote! {
+                    let request = request.header(#name, #ident)?;
+                },
+                false => quote! {
+                    let request = match #ident {
+                        Some(value) => request.header(#name, value)?,
+                        None => request,
+                    };
+                },
+            }
+        });
+
+        quote! { #(#headers)* }
+    }
+}
+
+impl Printable for ClientMethod {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let path_name = to_snake_ident(&self.name);
+        let http_method = format_ident!("{}", self.method.to_string());
+        let description = match &self.description {
+            Some(description) => print_doc(description),
+            None => quote! {},
+        };
+
+        let path_args = self.path_params.iter().map(|param| {
+            let ident = Self::param_ident(param);
+            let type_stream = Self::param_type(param);
+
+            quote! { #ident: #type_stream, }
+        });
+        let header_args = self.header_params.iter().map(|param| {
+            let ident = Self::param_ident(param);
+            let type_stream = Self::param_type(param);
+
+            quote! { #ident: #type_stream, }
+        });
+        let (query_arg, query) = match self.has_query {
+            true => (
+                quote! { query: &paths::#path_name::QueryParams, },
+                quote! { let request = request.query(query)?; },
+            ),
+            false => (quote! {}, quote! {}),
+        };
+        let (cookies_arg, cookies) = match self.has_cookies {
+            true => (
+                quote! { cookies: &paths::#path_name::CookieParams, },
+                quote! { let request = request.cookies(cookies)?; },
+            ),
+            false => (quote! {}, quote! {}),
+        };
+        let (body_arg, body) = match &self.request_body {
+            Some(request_body) => {
+                let body_name = to_pascal_ident(request_body);
+
+                (
+                    quote! { body: &request_bodies::#body_name, },
+                    quote! { let request = request.json(body)?; },
+                )
+            }
+            None => (quote! {}, quote! {}),
+        };
+        let url = self.print_url();
+        let headers = self.print_headers();
+
+        quote! {
+            #description
+            pub async fn #path_name(
+                &self,
+                #(#path_args)*
+                #query_arg
+                #(#header_args)*
+                #cookies_arg
+                #body_arg
+            ) -> Result<paths::#path_name::Response, ClientError> {
+                let request = ClientRequest::new(Method::#http_method, #url);
+                #query
+                #headers
+                #cookies
+                #body
+                let response = self.http.send(request).await?;
+
+                paths::#path_name::Response::from_response(&response)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::printer::client::ClientModule;
+    use crate::test::shot;
+    use insta::assert_snapshot;
+
+    fn client(method: ClientMethod) -> ClientModule {
+        ClientModule {
+            api_name: "test_api".to_owned(),
+            description: None,
+            methods: vec![method],
+        }
+    }
+
+    #[test]
+    fn method_without_params() {
+        let method = ClientMethod {
+            method: HttpMethod::Get,
+            path: "/session".to_owned(),
+            name: "sessionGet".to_owned(),
+            description: None,
+            request_body: None,
+            path_params: vec![],
+            header_params: vec![],
+            has_query: false,
+            has_cookies: false,
+        };
+
+        assert_snapshot!(shot(client(method)), @r###"
+        pub mod client {
+            use super::components::{parameters, request_bodies};
+            use super::paths;
+            use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+            pub struct TestApiClient<C = actix_swagger::client::AwcClient> {
+                base_url: String,
+                http: C,
+            }
+            impl TestApiClient {
+                pub fn new<U: Into<String>>(base_url: U) -> Self {
+                    Self::with_http(base_url, Default::default())
+                }
+            }
+            impl<C: HttpClient> TestApiClient<C> {
+                pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_owned();
+                    Self { base_url, http }
+                }
+                pub async fn session_get(
+                    &self,
+                ) -> Result<paths::session_get::Response, ClientError> {
+                    let request = ClientRequest::new(
+                        Method::GET,
+                        format!("{}/session", self.base_url),
+                    );
+                    let response = self.http.send(request).await?;
+                    paths::session_get::Response::from_response(&response)
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn method_with_every_param() {
+        let method = ClientMethod {
+            method: HttpMethod::Put,
+            path: "/users/{userId}/posts/{postId}".to_owned(),
+            name: "postUpdate".to_owned(),
+            description: Some("Update post of the user".to_owned()),
+            request_body: Some("PostUpdate".to_owned()),
+            path_params: vec![
+                PathParam {
+                    name: "userId".to_owned(),
+                    type_ref: "UserId".to_owned(),
+                    description: None,
+                    required: true,
+                },
+                PathParam {
+                    name: "postId".to_owned(),
+                    type_ref: "PostId".to_owned(),
+                    description: None,
+                    required: true,
+                },
+            ],
+            header_params: vec![
+                HeaderParam {
+                    name: "X-Request-Id".to_owned(),
+                    type_ref: "RequestId".to_owned(),
+                    description: None,
+                    required: true,
+                },
+                HeaderParam {
+                    name: "X-Trace".to_owned(),
+                    type_ref: "Trace".to_owned(),
+                    description: None,
+                    required: false,
+                },
+            ],
+            has_query: true,
+            has_cookies: true,
+        };
+
+        assert_snapshot!(shot(client(method)), @r###"
+        pub mod client {
+            use super::components::{parameters, request_bodies};
+            use super::paths;
+            use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+            pub struct TestApiClient<C = actix_swagger::client::AwcClient> {
+                base_url: String,
+                http: C,
+            }
+            impl TestApiClient {
+                pub fn new<U: Into<String>>(base_url: U) -> Self {
+                    Self::with_http(base_url, Default::default())
+                }
+            }
+            impl<C: HttpClient> TestApiClient<C> {
+                pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_owned();
+                    Self { base_url, http }
+                }
+                /// Update post of the user
+                pub async fn post_update(
+                    &self,
+                    user_id: &parameters::UserId,
+                    post_id: &parameters::PostId,
+                    query: &paths::post_update::QueryParams,
+                    x_request_id: &parameters::RequestId,
+                    x_trace: Option<&parameters::Trace>,
+                    cookies: &paths::post_update::CookieParams,
+                    body: &request_bodies::PostUpdate,
+                ) -> Result<paths::post_update::Response, ClientError> {
+                    let request = ClientRequest::new(
+                        Method::PUT,
+                        format!(
+                            "{}/users/{}/posts/{}", self.base_url,
+                            actix_swagger::client::path_segment(user_id) ?,
+                            actix_swagger::client::path_segment(post_id) ?
+                        ),
+                    );
+                    let request = request.query(query)?;
+                    let request = request.header("X-Request-Id", x_request_id)?;
+                    let request = match x_trace {
+                        Some(value) => request.header("X-Trace", value)?,
+                        None => request,
+                    };
+                    let request = request.cookies(cookies)?;
+                    let request = request.json(body)?;
+                    let response = self.http.send(request).await?;
+                    paths::post_update::Response::from_response(&response)
+                }
+            }
+        }
+        "###);
+    }
+}
@@ -0,0 +1,5 @@
+pub use methods::*;
+pub use module::*;
+
+pub mod methods;
+pub mod module;
@@ -0,0 +1,97 @@
+use super::methods::ClientMethod;
+use crate::printer::{api::to_struct_name, ident::to_type_ident, print_doc, Printable};
+use quote::quote;
+
+/// Client for the same api, printed to `client` module
+pub struct ClientModule {
+    pub api_name: String,
+    pub description: Option<String>,
+    pub methods: Vec<ClientMethod>,
+}
+
+impl Default for ClientModule {
+    fn default() -> Self {
+        Self {
+            api_name: "Api".to_owned(),
+            description: None,
+            methods: vec![],
+        }
+    }
+}
+
+impl Printable for ClientModule {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let client_name = to_type_ident(&format!(
+            "{}Client",
+            to_struct_name(self.api_name.to_owned())
+        ));
+        let doc_stream = match &self.description {
+            Some(description) => print_doc(description),
+            None => quote! {},
+        };
+        let methods = self.methods.print();
+
+        quote! {
+            pub mod client {
+                use super::components::{parameters, request_bodies};
+                use super::paths;
+                use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+
+                #doc_stream
+                pub struct #client_name<C = actix_swagger::client::AwcClient> {
+                    base_url: String,
+                    http: C,
+                }
+
+                impl #client_name {
+                    pub fn new<U: Into<String>>(base_url: U) -> Self {
+                        Self::with_http(base_url, Default::default())
+                    }
+                }
+
+                impl<C: HttpClient> #client_name<C> {
+                    pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                        let base_url = base_url.into().trim_end_matches('/').to_owned();
+
+                        Self { base_url, http }
+                    }
+
+                    #methods
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::shot;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn default_client_module() {
+        assert_snapshot!(shot(ClientModule::default()), @r###"
+        pub mod client {
+            use super::components::{parameters, request_bodies};
+            use super::paths;
+            use actix_swagger::client::{ClientError, ClientRequest, HttpClient, Method};
+            pub struct ApiClient<C = actix_swagger::client::AwcClient> {
+                base_url: String,
+                http: C,
+            }
+            impl ApiClient {
+                pub fn new<U: Into<String>>(base_url: U) -> Self {
+                    Self::with_http(base_url, Default::default())
+                }
+            }
+            impl<C: HttpClient> ApiClient<C> {
+                pub fn with_http<U: Into<String>>(base_url: U, http: C) -> Self {
+                    let base_url = base_url.into().trim_end_matches('/').to_owned();
+                    Self { base_url, http }
+                }
+            }
+        }
+        "###);
+    }
+}
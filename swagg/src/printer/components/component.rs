@@ -1,5 +1,7 @@
-use crate::printer::Printable;
-use inflections::Inflect;
+use crate::printer::{
+    ident::{to_pascal_ident, to_snake_ident, to_type_ident, unraw},
+    print_doc, Printable,
+};
 use quote::{format_ident, quote};
 
 pub enum Component {
@@ -13,6 +15,18 @@ pub enum Component {
         description: Option<String>,
         variants: Vec<EnumVariant>,
     },
+    /// Enum of integers or numbers, serialized as its value
+    ValueEnum {
+        name: String,
+        description: Option<String>,
+        variants: Vec<ValueVariant>,
+    },
+    /// Marker type with the only allowed value
+    Const {
+        name: String,
+        description: Option<String>,
+        value: EnumValue,
+    },
     Type {
         name: String,
         description: Option<String>,
@@ -25,6 +39,8 @@ impl Component {
         match self {
             Component::Object { description, .. } => description.clone(),
             Component::Enum { description, .. } => description.clone(),
+            Component::ValueEnum { description, .. } => description.clone(),
+            Component::Const { description, .. } => description.clone(),
             Component::Type { description, .. } => description.clone(),
         }
     }
@@ -33,6 +49,8 @@ impl Component {
         match self {
             Component::Object { name, .. } => name.clone(),
             Component::Enum { name, .. } => name.clone(),
+            Component::ValueEnum { name, .. } => name.clone(),
+            Component::Const { name, .. } => name.clone(),
             Component::Type { name, .. } => name.clone(),
         }
     }
@@ -40,9 +58,9 @@ impl Component {
 
 impl Printable for Component {
     fn print(&self) -> proc_macro2::TokenStream {
-        let name_ident = format_ident!("{}", self.name().to_pascal_case());
+        let name_ident = to_pascal_ident(&self.name());
         let description = match self.description() {
-            Some(description) => quote! { #[doc = #description] },
+            Some(description) => print_doc(&description),
             None => quote! {},
         };
 
@@ -50,14 +68,78 @@ impl Printable for Component {
 
         match self {
             Component::Object { fields, .. } => {
-                let fields_stream = fields.print();
+                let fields_stream = fields.iter().map(|field| field.print_in(&name_ident));
+
+                let defaults: Vec<_> = fields
+                    .iter()
+                    .filter_map(|field| {
+                        let value = field.default_value()?;
+                        let function = field.default_function();
+                        let type_stream = field.field_type.print();
+
+                        Some(quote! {
+                            pub fn #function() -> #type_stream {
+                                #value
+                            }
+                        })
+                    })
+                    .collect();
+
+                // Struct can be created empty, when every field has default or is optional
+                let is_defaultable = fields
+                    .iter()
+                    .all(|field| field.default.is_some() || !field.required);
+
+                let derive = match is_defaultable && defaults.is_empty() {
+                    true => quote! { #[derive(Debug, Default, Serialize, Deserialize)] },
+                    false => quote! { #[derive(Debug, Serialize, Deserialize)] },
+                };
+
+                let impl_defaults = match defaults.is_empty() {
+                    true => quote! {},
+                    false => quote! {
+                        impl #name_ident {
+                            #(#defaults)*
+                        }
+                    },
+                };
+
+                let impl_default = match is_defaultable && !defaults.is_empty() {
+                    true => {
+                        let values = fields.iter().map(|field| {
+                            let ident = to_snake_ident(&field.name);
+
+                            match field.default {
+                                Some(_) => {
+                                    let function = field.default_function();
+                                    quote! { #ident: Self::#function(), }
+                                }
+                                None => quote! { #ident: None, },
+                            }
+                        });
+
+                        quote! {
+                            impl Default for #name_ident {
+                                fn default() -> Self {
+                                    Self {
+                                        #(#values)*
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    false => quote! {},
+                };
 
                 quote! {
                     #description
-                    #[derive(Debug, Serialize, Deserialize)]
+                    #derive
                     pub struct #name_ident {
-                        #fields_stream
+                        #(#fields_stream)*
                     }
+
+                    #impl_defaults
+                    #impl_default
                 }
             }
             Component::Enum { variants, .. } => {
@@ -71,6 +153,99 @@ impl Printable for Component {
                     }
                 }
             }
+            Component::ValueEnum { variants, .. } => {
+                let is_integer = variants
+                    .iter()
+                    .all(|variant| matches!(variant.value, EnumValue::Integer(_)));
+                let value_type = match is_integer {
+                    true => quote! { i64 },
+                    false => quote! { f64 },
+                };
+                let (derive, repr) = match is_integer {
+                    true => (quote! { Copy, PartialEq, Eq }, quote! { #[repr(i64)] }),
+                    false => (quote! { Copy, PartialEq }, quote! {}),
+                };
+                let declarations = variants.iter().map(|variant| {
+                    let description = variant.description();
+                    let ident = variant.ident();
+                    let value = variant.value.print();
+
+                    match is_integer {
+                        true => quote! { #description #ident = #value, },
+                        false => quote! { #description #ident, },
+                    }
+                });
+                let idents: Vec<_> = variants.iter().map(|variant| variant.ident()).collect();
+                let values: Vec<_> = variants
+                    .iter()
+                    .map(|variant| variant.value.print())
+                    .collect();
+
+                quote! {
+                    #description
+                    #[derive(Debug, Clone, #derive)]
+                    #repr
+                    pub enum #name_ident {
+                        #(#declarations)*
+                    }
+
+                    impl Serialize for #name_ident {
+                        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                            let value: #value_type = match self {
+                                #(Self::#idents => #values,)*
+                            };
+
+                            value.serialize(serializer)
+                        }
+                    }
+
+                    impl<'de> Deserialize<'de> for #name_ident {
+                        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                            let value = #value_type::deserialize(deserializer)?;
+
+                            #(if value == #values {
+                                return Ok(Self::#idents);
+                            })*
+
+                            Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+                        }
+                    }
+                }
+            }
+            Component::Const { value, .. } => {
+                let (value_type, owned_type) = match value {
+                    EnumValue::String(_) => (quote! { &'static str }, quote! { String }),
+                    EnumValue::Integer(_) => (quote! { i64 }, quote! { i64 }),
+                    EnumValue::Number(_) => (quote! { f64 }, quote! { f64 }),
+                    EnumValue::Boolean(_) => (quote! { bool }, quote! { bool }),
+                };
+                let value = value.print();
+
+                quote! {
+                    #description
+                    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+                    pub struct #name_ident;
+
+                    impl #name_ident {
+                        pub const VALUE: #value_type = #value;
+                    }
+
+                    impl Serialize for #name_ident {
+                        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                            Self::VALUE.serialize(serializer)
+                        }
+                    }
+
+                    impl<'de> Deserialize<'de> for #name_ident {
+                        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                            match #owned_type::deserialize(deserializer)? == Self::VALUE {
+                                true => Ok(Self),
+                                false => Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE))),
+                            }
+                        }
+                    }
+                }
+            }
             Component::Type { type_value, .. } => {
                 let type_stream = type_value.print();
 
@@ -83,25 +258,75 @@ impl Printable for Component {
     }
 }
 
+/// Value of the enum variant or constant
+#[derive(Debug, Clone)]
+pub enum EnumValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+    Boolean(bool),
+}
+
+impl Printable for EnumValue {
+    fn print(&self) -> proc_macro2::TokenStream {
+        match self {
+            EnumValue::String(value) => quote! { #value },
+            EnumValue::Integer(value) => {
+                let literal = proc_macro2::Literal::i64_unsuffixed(*value);
+                quote! { #literal }
+            }
+            EnumValue::Number(value) => {
+                let literal = proc_macro2::Literal::f64_unsuffixed(*value);
+                quote! { #literal }
+            }
+            EnumValue::Boolean(value) => quote! { #value },
+        }
+    }
+}
+
+/// Variant of the integer or number enum
+pub struct ValueVariant {
+    /// Name of the variant, from `x-enum-varnames` or the value itself
+    pub name: String,
+    pub description: Option<String>,
+    pub value: EnumValue,
+}
+
+impl ValueVariant {
+    fn ident(&self) -> proc_macro2::Ident {
+        to_pascal_ident(&self.name)
+    }
+
+    fn description(&self) -> proc_macro2::TokenStream {
+        match &self.description {
+            Some(descr) => print_doc(descr),
+            None => quote! {},
+        }
+    }
+}
+
 pub struct EnumVariant {
+    /// Value of the variant, kept in `#[serde(rename)]`
     pub name: String,
     pub description: Option<String>,
+
+    /// Variant can be renamed with `x-enum-varnames`
+    pub x_variant_name: Option<String>,
 }
 
 impl Printable for EnumVariant {
     fn print(&self) -> proc_macro2::TokenStream {
         let name_original = self.name.clone();
-        let name_pascal = name_original.to_pascal_case();
-        let name_ident = format_ident!("{}", name_pascal);
+        let name_ident = to_pascal_ident(self.x_variant_name.as_ref().unwrap_or(&name_original));
 
-        let is_pascal_diffs = name_pascal != name_original;
+        let is_pascal_diffs = unraw(&name_ident) != name_original;
         let rename = match is_pascal_diffs {
             true => quote! { #[serde(rename = #name_original)] },
             false => quote! {},
         };
 
         let description = match &self.description {
-            Some(descr) => quote! { #[doc = #descr]},
+            Some(descr) => print_doc(descr),
             None => quote! {},
         };
 
@@ -126,36 +351,116 @@ pub struct Field {
     pub description: Option<String>,
 
     pub field_type: FieldType,
+
+    /// Value from `default`, field is not optional then
+    pub default: Option<DefaultValue>,
+
+    /// Field is only deserialized, `writeOnly` on the server or `readOnly` in the client
+    pub skip_serializing: bool,
 }
 
-impl Printable for Field {
-    fn print(&self) -> proc_macro2::TokenStream {
+impl Field {
+    /// Field of the struct `owner`, default value is created by its function
+    fn print_in(&self, owner: &proc_macro2::Ident) -> proc_macro2::TokenStream {
         let name_original = self.name.clone();
-        let name_snake = name_original.to_snake_case();
-        let name_ident = format_ident!("{}", name_snake);
+        let name_ident = to_snake_ident(&name_original);
 
-        let is_snake_diffs = name_snake != name_original;
+        let is_snake_diffs = unraw(&name_ident) != name_original;
         let rename = match is_snake_diffs {
             true => quote! { #[serde(rename = #name_original)] },
             false => quote! {},
         };
 
         let description = match &self.description {
-            Some(descr) => quote! { #[doc = #descr]},
+            Some(descr) => print_doc(descr),
             None => quote! {},
         };
         let type_stream = self.field_type.print();
-        let type_value = match self.required {
+        let type_value = match self.required || self.default.is_some() {
             false => quote! { Option<#type_stream> },
             true => type_stream,
         };
+        let default = match self.default {
+            Some(_) => {
+                let path = format!("{}::{}", owner, self.default_function());
+                quote! { #[serde(default = #path)] }
+            }
+            None => quote! {},
+        };
+        let skip = match self.skip_serializing {
+            true => quote! { #[serde(skip_serializing)] },
+            false => quote! {},
+        };
 
         quote! {
             #description
             #rename
+            #default
+            #skip
             pub #name_ident: #type_value,
         }
     }
+
+    fn default_function(&self) -> proc_macro2::Ident {
+        format_ident!("default_{}", unraw(&to_snake_ident(&self.name)))
+    }
+
+    /// Expression with the default value of the field type
+    fn default_value(&self) -> Option<proc_macro2::TokenStream> {
+        self.default
+            .as_ref()
+            .map(|default| default.print_as(&self.field_type))
+    }
+}
+
+/// Value of the field from `default`, already checked against the field type
+pub enum DefaultValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+    Boolean(bool),
+
+    /// Variant of the enum, by the name from specification
+    Variant(String),
+
+    /// Items of the list, `vec![...]`
+    List(Vec<DefaultValue>),
+
+    /// Empty list, object or marker type, `Default::default()`
+    Empty,
+}
+
+impl DefaultValue {
+    fn print_as(&self, field_type: &FieldType) -> proc_macro2::TokenStream {
+        match (self, field_type) {
+            (value, FieldType::Boxed(inner)) => {
+                let value = value.print_as(inner);
+                quote! { Box::new(#value) }
+            }
+            (DefaultValue::String(value), _) => quote! { #value.to_owned() },
+            (DefaultValue::Integer(value), _) => {
+                let literal = proc_macro2::Literal::i64_unsuffixed(*value);
+                quote! { #literal }
+            }
+            (DefaultValue::Number(value), _) => {
+                let literal = proc_macro2::Literal::f64_unsuffixed(*value);
+                quote! { #literal }
+            }
+            (DefaultValue::Boolean(value), _) => quote! { #value },
+            (DefaultValue::Variant(variant), field_type) => {
+                let type_stream = field_type.print();
+                let variant_ident = to_pascal_ident(variant);
+                quote! { #type_stream::#variant_ident }
+            }
+            (DefaultValue::List(items), FieldType::Array(inner)) => {
+                let items = items.iter().map(|item| item.print_as(inner));
+                quote! { vec![#(#items),*] }
+            }
+            (DefaultValue::List(_), _) | (DefaultValue::Empty, _) => {
+                quote! { Default::default() }
+            }
+        }
+    }
 }
 
 pub enum FieldType {
@@ -166,6 +471,9 @@ pub enum FieldType {
 
     Array(Box<FieldType>),
 
+    /// Recursive type, printed as `Box<T>`
+    Boxed(Box<FieldType>),
+
     /// Should be used with `x-rust-type: crate::app::MyType`
     /// MyType must implement Debug, Serialize, Deserialize
     Internal(String),
@@ -176,13 +484,17 @@ impl Printable for FieldType {
         match self {
             FieldType::Native(native) => native.print(),
             FieldType::Custom(name) => {
-                let name_ident = format_ident!("{}", name);
+                let name_ident = to_type_ident(name);
                 quote! { #name_ident }
             }
             FieldType::Array(inner_type) => {
                 let inner_type_stream = inner_type.print();
                 quote! { Vec<#inner_type_stream> }
             }
+            FieldType::Boxed(inner_type) => {
+                let inner_type_stream = inner_type.print();
+                quote! { Box<#inner_type_stream> }
+            }
             FieldType::Internal(name) => path_to_stream(name.clone()),
         }
     }
@@ -233,7 +545,10 @@ impl Printable for NativeType {
     }
 }
 
+#[derive(Default)]
+#[allow(dead_code)]
 pub enum FormatString {
+    #[default]
     None,
     Binary,
     Byte,
@@ -249,12 +564,6 @@ pub enum FormatString {
     Pattern(regex::Regex),
 }
 
-impl Default for FormatString {
-    fn default() -> FormatString {
-        FormatString::None
-    }
-}
-
 impl Printable for FormatString {
     fn print(&self) -> proc_macro2::TokenStream {
         // Any string format now compiles to String
@@ -262,17 +571,14 @@ impl Printable for FormatString {
     }
 }
 
+#[derive(Default)]
+#[allow(dead_code)]
 pub enum FormatInteger {
+    #[default]
     Int32,
     Int64,
 }
 
-impl Default for FormatInteger {
-    fn default() -> FormatInteger {
-        FormatInteger::Int32
-    }
-}
-
 impl Printable for FormatInteger {
     fn print(&self) -> proc_macro2::TokenStream {
         match self {
@@ -282,17 +588,14 @@ impl Printable for FormatInteger {
     }
 }
 
+#[derive(Default)]
+#[allow(dead_code)]
 pub enum FormatFloat {
+    #[default]
     Float,
     Double,
 }
 
-impl Default for FormatFloat {
-    fn default() -> FormatFloat {
-        FormatFloat::Float
-    }
-}
-
 impl Printable for FormatFloat {
     fn print(&self) -> proc_macro2::TokenStream {
         match self {
@@ -428,6 +731,11 @@ mod tests {
         assert_snapshot!(shot(TestType(FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Internal("crate::Super".to_owned()))))))), @r"type Test = Vec<Vec<crate::Super>>;");
     }
 
+    #[test]
+    fn field_type_boxed() {
+        assert_snapshot!(shot(TestType(FieldType::Boxed(Box::new(FieldType::Custom("TreeNode".to_owned()))))), @"type Test = Box<TreeNode>;");
+    }
+
     #[test]
     fn component_type() {
         assert_snapshot!(shot(Component::Type {
@@ -441,7 +749,7 @@ mod tests {
             description: Some("Example description for test type export".to_owned()),
             type_value: FieldType::Internal("super::another::Type".to_owned()),
         }), @r###"
-        #[doc = "Example description for test type export"]
+        /// Example description for test type export
         pub type UpperCaseName = super::another::Type;
         "###);
     }
@@ -453,7 +761,7 @@ mod tests {
             description: None,
             fields: vec![],
         }), @r###"
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Default, Serialize, Deserialize)]
         pub struct SnakeCaseName {}
         "###);
 
@@ -462,8 +770,9 @@ mod tests {
             description: Some("My super long description.\nOr not".to_owned()),
             fields: vec![],
         }), @r###"
-        #[doc = "My super long description.\nOr not"]
-        #[derive(Debug, Serialize, Deserialize)]
+        /// My super long description.
+        /// Or not
+        #[derive(Debug, Default, Serialize, Deserialize)]
         pub struct UpperCaseName {}
         "###);
 
@@ -474,36 +783,43 @@ mod tests {
                 name: "UPPER_CASE_FIELD".to_owned(),
                 description: Some("Description".to_owned()),
                 required: true,
-                field_type: FieldType::Native(NativeType::String { format: Default::default() })
+                field_type: FieldType::Native(NativeType::String { format: Default::default() }),
+                default: None,
+                skip_serializing: false,
             },
             Field {
                 name: "snake_case_field".to_owned(),
                 description: None,
                 required: true,
-                field_type: FieldType::Native(NativeType::Integer { format: FormatInteger::Int64 })
+                field_type: FieldType::Native(NativeType::Integer { format: FormatInteger::Int64 }),
+                default: None,
+                skip_serializing: false,
             },
             Field {
                 name: "superCase".to_owned(),
                 description: None,
                 required: false,
                 field_type: FieldType::Internal("super::super::app::Type".to_owned()),
+                default: None,
+                skip_serializing: false,
             },
             Field {
                 name: "JustAnother".to_owned(),
                 description: Some("".to_owned()),
                 required: false,
-                field_type: FieldType::Array(Box::new(FieldType::Internal("i128".to_owned())))
+                field_type: FieldType::Array(Box::new(FieldType::Internal("i128".to_owned()))),
+                default: None,
+                skip_serializing: false,
             }],
         }), @r###"
         #[derive(Debug, Serialize, Deserialize)]
         pub struct ThisIsFields {
-            #[doc = "Description"]
+            /// Description
             #[serde(rename = "UPPER_CASE_FIELD")]
             pub upper_case_field: String,
             pub snake_case_field: i64,
             #[serde(rename = "superCase")]
             pub super_case: Option<super::super::app::Type>,
-            #[doc = ""]
             #[serde(rename = "JustAnother")]
             pub just_another: Option<Vec<i128>>,
         }
@@ -526,7 +842,8 @@ mod tests {
             description: Some("My super long description.\nOr not".to_owned()),
             variants: vec![],
         }), @r###"
-        #[doc = "My super long description.\nOr not"]
+        /// My super long description.
+        /// Or not
         #[derive(Debug, Serialize, Deserialize)]
         pub enum UpperCaseName {}
         "###);
@@ -537,32 +854,404 @@ mod tests {
             variants: vec![EnumVariant {
                 name: "UPPER_CASE_FIELD".to_owned(),
                 description: Some("Description".to_owned()),
+                x_variant_name: None,
             },
             EnumVariant {
                 name: "snake_case_field".to_owned(),
                 description: None,
+                x_variant_name: None,
             },
             EnumVariant {
                 name: "superCase".to_owned(),
                 description: None,
+                x_variant_name: None,
             },
             EnumVariant {
                 name: "JustAnother".to_owned(),
                 description: Some("".to_owned()),
+                x_variant_name: None,
             }],
         }), @r###"
         #[derive(Debug, Serialize, Deserialize)]
         pub enum ThisIsFields {
-            #[doc = "Description"]
+            /// Description
             #[serde(rename = "UPPER_CASE_FIELD")]
             UpperCaseField,
             #[serde(rename = "snake_case_field")]
             SnakeCaseField,
             #[serde(rename = "superCase")]
             SuperCase,
-            #[doc = ""]
             JustAnother,
         }
         "###);
     }
+
+    #[test]
+    fn component_with_invalid_names() {
+        assert_snapshot!(shot(Component::Object {
+            name: "2fa".to_owned(),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "type".to_owned(),
+                    required: true,
+                    description: None,
+                    field_type: FieldType::Custom("Self".to_owned()),
+                    default: None,
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "self".to_owned(),
+                    required: false,
+                    description: None,
+                    field_type: FieldType::Custom("2fa".to_owned()),
+                    default: None,
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "2fa-code".to_owned(),
+                    required: true,
+                    description: None,
+                    field_type: FieldType::Native(NativeType::Boolean),
+                    default: None,
+                    skip_serializing: false,
+                },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct Value2fa {
+            pub r#type: Self_,
+            #[serde(rename = "self")]
+            pub self_: Option<Value2fa>,
+            #[serde(rename = "2fa-code")]
+            pub _2fa_code: bool,
+        }
+        "###);
+
+        assert_snapshot!(shot(Component::Enum {
+            name: "Vote".to_owned(),
+            description: None,
+            variants: vec![
+                EnumVariant { name: "+1".to_owned(), description: None, x_variant_name: None },
+                EnumVariant { name: "-1".to_owned(), description: None, x_variant_name: None },
+                EnumVariant { name: "".to_owned(), description: None, x_variant_name: None },
+                EnumVariant { name: "Self".to_owned(), description: None, x_variant_name: None },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum Vote {
+            #[serde(rename = "+1")]
+            Plus1,
+            #[serde(rename = "-1")]
+            Minus1,
+            #[serde(rename = "")]
+            Empty,
+            #[serde(rename = "Self")]
+            Self_,
+        }
+        "###);
+    }
+
+    #[test]
+    fn component_enum_variant_names() {
+        assert_snapshot!(shot(Component::Enum {
+            name: "Status".to_owned(),
+            description: None,
+            variants: vec![
+                EnumVariant { name: "a".to_owned(), description: None, x_variant_name: Some("active".to_owned()) },
+                EnumVariant { name: "Blocked".to_owned(), description: None, x_variant_name: Some("Blocked".to_owned()) },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum Status {
+            #[serde(rename = "a")]
+            Active,
+            Blocked,
+        }
+        "###);
+    }
+
+    #[test]
+    fn component_value_enum() {
+        assert_snapshot!(shot(Component::ValueEnum {
+            name: "Priority".to_owned(),
+            description: Some("Priority of the task".to_owned()),
+            variants: vec![
+                ValueVariant { name: "low".to_owned(), description: None, value: EnumValue::Integer(1) },
+                ValueVariant { name: "-1".to_owned(), description: None, value: EnumValue::Integer(-1) },
+            ],
+        }), @r###"
+        /// Priority of the task
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(i64)]
+        pub enum Priority {
+            Low = 1,
+            Minus1 = -1,
+        }
+        impl Serialize for Priority {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value: i64 = match self {
+                    Self::Low => 1,
+                    Self::Minus1 => -1,
+                };
+                value.serialize(serializer)
+            }
+        }
+        impl<'de> Deserialize<'de> for Priority {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let value = i64::deserialize(deserializer)?;
+                if value == 1 {
+                    return Ok(Self::Low);
+                }
+                if value == -1 {
+                    return Ok(Self::Minus1);
+                }
+                Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+            }
+        }
+        "###);
+
+        assert_snapshot!(shot(Component::ValueEnum {
+            name: "Ratio".to_owned(),
+            description: None,
+            variants: vec![
+                ValueVariant { name: "half".to_owned(), description: None, value: EnumValue::Number(0.5) },
+                ValueVariant { name: "full".to_owned(), description: None, value: EnumValue::Number(1.0) },
+            ],
+        }), @r###"
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum Ratio {
+            Half,
+            Full,
+        }
+        impl Serialize for Ratio {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value: f64 = match self {
+                    Self::Half => 0.5,
+                    Self::Full => 1.0,
+                };
+                value.serialize(serializer)
+            }
+        }
+        impl<'de> Deserialize<'de> for Ratio {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let value = f64::deserialize(deserializer)?;
+                if value == 0.5 {
+                    return Ok(Self::Half);
+                }
+                if value == 1.0 {
+                    return Ok(Self::Full);
+                }
+                Err(serde::de::Error::custom(format!("unknown variant {}", value)))
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn component_const() {
+        assert_snapshot!(shot(Component::Const {
+            name: "kind".to_owned(),
+            description: None,
+            value: EnumValue::String("user".to_owned()),
+        }), @r###"
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub struct Kind;
+        impl Kind {
+            pub const VALUE: &'static str = "user";
+        }
+        impl Serialize for Kind {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Self::VALUE.serialize(serializer)
+            }
+        }
+        impl<'de> Deserialize<'de> for Kind {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                match String::deserialize(deserializer)? == Self::VALUE {
+                    true => Ok(Self),
+                    false => Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE))),
+                }
+            }
+        }
+        "###);
+
+        assert_snapshot!(shot(Component::Const {
+            name: "Version".to_owned(),
+            description: None,
+            value: EnumValue::Integer(2),
+        }), @r###"
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub struct Version;
+        impl Version {
+            pub const VALUE: i64 = 2;
+        }
+        impl Serialize for Version {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Self::VALUE.serialize(serializer)
+            }
+        }
+        impl<'de> Deserialize<'de> for Version {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                match i64::deserialize(deserializer)? == Self::VALUE {
+                    true => Ok(Self),
+                    false => Err(serde::de::Error::custom(format!("expected {:?}", Self::VALUE))),
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn component_object_defaults() {
+        assert_snapshot!(shot(Component::Object {
+            name: "Settings".to_owned(),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "page-size".to_owned(),
+                    required: false,
+                    description: None,
+                    field_type: FieldType::Native(NativeType::Integer { format: FormatInteger::Int32 }),
+                    default: Some(DefaultValue::Integer(20)),
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "type".to_owned(),
+                    required: true,
+                    description: None,
+                    field_type: FieldType::Custom("Kind".to_owned()),
+                    default: Some(DefaultValue::Variant("user".to_owned())),
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "title".to_owned(),
+                    required: false,
+                    description: None,
+                    field_type: FieldType::Native(NativeType::String { format: FormatString::None }),
+                    default: None,
+                    skip_serializing: false,
+                },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct Settings {
+            #[serde(rename = "page-size")]
+            #[serde(default = "Settings::default_page_size")]
+            pub page_size: i32,
+            #[serde(default = "Settings::default_type")]
+            pub r#type: Kind,
+            pub title: Option<String>,
+        }
+        impl Settings {
+            pub fn default_page_size() -> i32 {
+                20
+            }
+            pub fn default_type() -> Kind {
+                Kind::User
+            }
+        }
+        impl Default for Settings {
+            fn default() -> Self {
+                Self {
+                    page_size: Self::default_page_size(),
+                    r#type: Self::default_type(),
+                    title: None,
+                }
+            }
+        }
+        "###);
+
+        assert_snapshot!(shot(Component::Object {
+            name: "Filter".to_owned(),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "tags".to_owned(),
+                    required: true,
+                    description: None,
+                    field_type: FieldType::Array(Box::new(FieldType::Custom("Tag".to_owned()))),
+                    default: Some(DefaultValue::Empty),
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "query".to_owned(),
+                    required: true,
+                    description: None,
+                    field_type: FieldType::Native(NativeType::String { format: FormatString::None }),
+                    default: None,
+                    skip_serializing: false,
+                },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct Filter {
+            #[serde(default = "Filter::default_tags")]
+            pub tags: Vec<Tag>,
+            pub query: String,
+        }
+        impl Filter {
+            pub fn default_tags() -> Vec<Tag> {
+                Default::default()
+            }
+        }
+        "###);
+
+        assert_snapshot!(shot(Component::Object {
+            name: "Search".to_owned(),
+            description: None,
+            fields: vec![
+                Field {
+                    name: "tags".to_owned(),
+                    required: false,
+                    description: None,
+                    field_type: FieldType::Array(Box::new(FieldType::Native(NativeType::String { format: FormatString::None }))),
+                    default: Some(DefaultValue::List(vec![
+                        DefaultValue::String("new".to_owned()),
+                        DefaultValue::String("hot".to_owned()),
+                    ])),
+                    skip_serializing: false,
+                },
+                Field {
+                    name: "roles".to_owned(),
+                    required: false,
+                    description: None,
+                    field_type: FieldType::Array(Box::new(FieldType::Custom("Role".to_owned()))),
+                    default: Some(DefaultValue::List(vec![DefaultValue::Variant("user".to_owned())])),
+                    skip_serializing: false,
+                },
+            ],
+        }), @r###"
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct Search {
+            #[serde(default = "Search::default_tags")]
+            pub tags: Vec<String>,
+            #[serde(default = "Search::default_roles")]
+            pub roles: Vec<Role>,
+        }
+        impl Search {
+            pub fn default_tags() -> Vec<String> {
+                vec!["new".to_owned(), "hot".to_owned()]
+            }
+            pub fn default_roles() -> Vec<Role> {
+                vec![Role::User]
+            }
+        }
+        impl Default for Search {
+            fn default() -> Self {
+                Self {
+                    tags: Self::default_tags(),
+                    roles: Self::default_roles(),
+                }
+            }
+        }
+        "###);
+    }
 }
@@ -1,5 +1,4 @@
 pub use component::*;
-pub use module::*;
 
 pub mod component;
 pub mod module;
@@ -7,3 +6,4 @@ pub mod parameters;
 pub mod request_bodies;
 pub mod responses;
 pub mod schemas;
+pub mod security_schemes;
@@ -2,6 +2,7 @@ use super::parameters::ParametersModule;
 use super::request_bodies::RequestBodiesModule;
 use super::responses::ResponsesModule;
 use super::schemas::SchemasModule;
+use super::security_schemes::SecuritySchemesModule;
 use crate::printer::Printable;
 use quote::quote;
 
@@ -11,6 +12,7 @@ pub struct ComponentsModule {
     pub request_bodies: RequestBodiesModule,
     pub responses: ResponsesModule,
     pub schemas: SchemasModule,
+    pub security_schemes: SecuritySchemesModule,
 }
 
 impl Printable for ComponentsModule {
@@ -19,6 +21,7 @@ impl Printable for ComponentsModule {
         let request_bodies = self.request_bodies.print();
         let responses = self.responses.print();
         let schemas = self.schemas.print();
+        let security_schemes = self.security_schemes.print();
 
         quote! {
             pub mod components {
@@ -26,6 +29,7 @@ impl Printable for ComponentsModule {
                 #request_bodies
                 #responses
                 #schemas
+                #security_schemes
             }
         }
     }
@@ -42,17 +46,18 @@ mod tests {
         assert_snapshot!(shot(ComponentsModule::default()), @r###"
         pub mod components {
             pub mod parameters {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod request_bodies {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod responses {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
             pub mod schemas {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
             }
+            pub mod security_schemes {}
         }
         "###);
     }
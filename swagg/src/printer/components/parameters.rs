@@ -41,10 +41,10 @@ mod tests {
             ]
         }), @r###"
         pub mod parameters {
-            use serde::{Deserialize, Serialize};
+            use serde::{Serialize, Deserialize};
             #[derive(Debug, Serialize, Deserialize)]
             pub enum Example {}
-            #[derive(Debug, Serialize, Deserialize)]
+            #[derive(Debug, Default, Serialize, Deserialize)]
             pub struct Test {}
         }
         "###);
@@ -0,0 +1,173 @@
+pub use module::*;
+
+use crate::printer::{ident::to_pascal_ident, print_doc, Printable};
+use quote::quote;
+
+/// Security scheme from `components.securitySchemes`
+///
+/// Printed as a trait that application implements for its identity type
+#[derive(Debug, Clone)]
+pub struct SecurityScheme {
+    /// Name of the scheme as it used in `security` requirements
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: SecuritySchemeKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum SecuritySchemeKind {
+    /// http `bearer`, `oauth2` and `openIdConnect`
+    Bearer,
+    /// http `basic`
+    Basic,
+    ApiKey {
+        location: ApiKeyLocation,
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+    Cookie,
+}
+
+impl SecurityScheme {
+    pub fn trait_name(&self) -> proc_macro2::Ident {
+        to_pascal_ident(&self.name)
+    }
+
+    fn credentials(&self) -> proc_macro2::TokenStream {
+        match self.kind {
+            SecuritySchemeKind::Bearer => quote! { actix_swagger::security::Bearer },
+            SecuritySchemeKind::Basic => quote! { actix_swagger::security::Basic },
+            SecuritySchemeKind::ApiKey { .. } => quote! { actix_swagger::security::ApiKey },
+        }
+    }
+
+    /// Expression of `actix_swagger::security::Scheme` describing where credentials are
+    pub fn print_scheme(&self) -> proc_macro2::TokenStream {
+        match &self.kind {
+            SecuritySchemeKind::Bearer => quote! { actix_swagger::security::Scheme::Bearer },
+            SecuritySchemeKind::Basic => quote! { actix_swagger::security::Scheme::Basic },
+            SecuritySchemeKind::ApiKey { location, name } => {
+                let location = match location {
+                    ApiKeyLocation::Header => quote! { Header },
+                    ApiKeyLocation::Query => quote! { Query },
+                    ApiKeyLocation::Cookie => quote! { Cookie },
+                };
+
+                quote! {
+                    actix_swagger::security::Scheme::ApiKey {
+                        location: actix_swagger::security::ApiKeyLocation::#location,
+                        name: #name,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Printable for SecurityScheme {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let trait_name = self.trait_name();
+        let credentials = self.credentials();
+        let description = match &self.description {
+            Some(description) => print_doc(description),
+            None => quote! {},
+        };
+
+        quote! {
+            #description
+            pub trait #trait_name: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: #credentials,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+        }
+    }
+}
+
+pub mod module {
+    use super::SecurityScheme;
+    use crate::printer::Printable;
+    use quote::quote;
+
+    #[derive(Default)]
+    pub struct SecuritySchemesModule {
+        pub list: Vec<SecurityScheme>,
+    }
+
+    impl Printable for SecuritySchemesModule {
+        fn print(&self) -> proc_macro2::TokenStream {
+            let schemes = self.list.print();
+
+            quote! {
+                pub mod security_schemes {
+                    #schemes
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::shot;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn security_schemes_with_every_kind() {
+        assert_snapshot!(shot(SecuritySchemesModule {
+            list: vec![
+                SecurityScheme {
+                    name: "bearerAuth".to_owned(),
+                    description: Some("JWT issued by auth service".to_owned()),
+                    kind: SecuritySchemeKind::Bearer,
+                },
+                SecurityScheme {
+                    name: "basic".to_owned(),
+                    description: None,
+                    kind: SecuritySchemeKind::Basic,
+                },
+                SecurityScheme {
+                    name: "api_key".to_owned(),
+                    description: None,
+                    kind: SecuritySchemeKind::ApiKey {
+                        location: ApiKeyLocation::Header,
+                        name: "X-Api-Key".to_owned(),
+                    },
+                },
+            ]
+        }), @r###"
+        pub mod security_schemes {
+            /// JWT issued by auth service
+            pub trait BearerAuth: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::Bearer,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+            pub trait Basic: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::Basic,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+            pub trait ApiKey: Clone + Sized + 'static {
+                fn authenticate(
+                    req: &actix_web::HttpRequest,
+                    credentials: actix_swagger::security::ApiKey,
+                    scopes: &'static [&'static str],
+                ) -> actix_swagger::security::AuthFuture<Self>;
+            }
+        }
+        "###);
+    }
+}
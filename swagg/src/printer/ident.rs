@@ -0,0 +1,137 @@
+//! Identifiers made from names in specification
+//!
+//! Any name can be used in specification: keywords, names with leading digits, `+1` or empty string,
+//! so every identifier is converted to the valid one. Printers keep original name in `#[serde(rename)]`
+
+use inflections::Inflect;
+use proc_macro2::Ident;
+use quote::format_ident;
+
+/// Keywords of the 2018 edition, including reserved ones
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Keywords which cannot be raw identifiers, `_` suffix is added instead
+const NOT_RAW: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Identifier of the field, module or function
+pub fn to_snake_ident(name: &str) -> Ident {
+    escape(&replace_invalid(&spell_symbols(name).to_snake_case()), "_")
+}
+
+/// Identifier of the type or enum variant
+pub fn to_pascal_ident(name: &str) -> Ident {
+    escape(
+        &replace_invalid(&spell_symbols(name).to_pascal_case()),
+        "Value",
+    )
+}
+
+/// Identifier of the type with name already converted to PascalCase, only keywords and digits are escaped
+pub fn to_type_ident(name: &str) -> Ident {
+    escape(name, "Value")
+}
+
+/// Name of the identifier without `r#`, the same name serde uses for fields and variants
+pub fn unraw(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").to_owned()
+}
+
+/// Symbols would be dropped by case conversion, so `+1` and `-1` are spelled,
+/// any other symbol separates words
+fn spell_symbols(name: &str) -> String {
+    let name = match name.strip_prefix('-') {
+        Some(rest) => format!("minus {}", rest),
+        None => name.to_owned(),
+    };
+
+    name.replace('+', " plus ")
+        .chars()
+        .map(|c| match c.is_alphanumeric() || c == '_' || c == '-' {
+            true => c,
+            false => ' ',
+        })
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
+
+/// Characters left after case conversion are replaced with `_`
+fn replace_invalid(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c.is_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+
+    name.trim_matches('_').to_owned()
+}
+
+fn escape(name: &str, digit_prefix: &str) -> Ident {
+    if name.is_empty() {
+        return format_ident!(
+            "{}",
+            match digit_prefix {
+                "_" => "empty",
+                _ => "Empty",
+            }
+        );
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return format_ident!("{}{}", digit_prefix, name);
+    }
+
+    match (KEYWORDS.contains(&name), NOT_RAW.contains(&name)) {
+        (true, true) => format_ident!("{}_", name),
+        (true, false) => format_ident!("r#{}", name),
+        _ => format_ident!("{}", name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snake(name: &str) -> String {
+        to_snake_ident(name).to_string()
+    }
+
+    fn pascal(name: &str) -> String {
+        to_pascal_ident(name).to_string()
+    }
+
+    #[test]
+    fn escapes_keywords() {
+        assert_eq!(snake("type"), "r#type");
+        assert_eq!(snake("ref"), "r#ref");
+        assert_eq!(snake("self"), "self_");
+        assert_eq!(snake("super"), "super_");
+        assert_eq!(pascal("self"), "Self_");
+        assert_eq!(unraw(&to_snake_ident("type")), "type");
+    }
+
+    #[test]
+    fn prefixes_leading_digits() {
+        assert_eq!(snake("2fa"), "_2fa");
+        assert_eq!(pascal("2fa"), "Value2fa");
+        assert_eq!(to_type_ident("200Ok").to_string(), "Value200Ok");
+    }
+
+    #[test]
+    fn spells_symbols() {
+        assert_eq!(pascal("+1"), "Plus1");
+        assert_eq!(pascal("-1"), "Minus1");
+        assert_eq!(pascal(""), "Empty");
+        assert_eq!(snake(""), "empty");
+        assert_eq!(snake("x-request-id"), "x_request_id");
+        assert_eq!(pascal("application/json"), "ApplicationJson");
+    }
+}
@@ -1,7 +1,19 @@
 pub mod api;
+pub mod client;
 pub mod components;
+pub mod ident;
 pub mod paths;
 
+/// Doc comment as attribute per line, so formatter prints every line as `///` comment
+pub fn print_doc(text: &str) -> proc_macro2::TokenStream {
+    let lines = text.lines().map(|line| match line.is_empty() {
+        true => String::new(),
+        false => format!(" {}", line),
+    });
+
+    quote::quote! { #(#[doc = #lines])* }
+}
+
 pub trait Printable {
     fn print(&self) -> proc_macro2::TokenStream;
 }
@@ -21,27 +33,66 @@ where
     }
 }
 
-#[derive(Default)]
 pub struct GeneratedModule {
     pub api: api::module::ApiModule,
+    pub client: Option<client::module::ClientModule>,
     pub components: components::module::ComponentsModule,
     pub paths: paths::module::PathsModule,
+
+    /// Print `api` module for actix-web server
+    pub server: bool,
+
+    /// Lints allowed for every generated module
+    pub lints: Vec<String>,
+}
+
+impl Default for GeneratedModule {
+    fn default() -> Self {
+        Self {
+            api: Default::default(),
+            client: None,
+            components: Default::default(),
+            paths: Default::default(),
+            server: true,
+            lints: vec!["dead_code".to_owned(), "unused_imports".to_owned()],
+        }
+    }
 }
 
 impl GeneratedModule {}
 
 impl Printable for GeneratedModule {
     fn print(&self) -> proc_macro2::TokenStream {
-        let api_module = self.api.print();
+        let api_module = match self.server {
+            true => self.api.print(),
+            false => quote::quote! {},
+        };
+        let client_module = match &self.client {
+            Some(client) => client.print(),
+            None => quote::quote! {},
+        };
+        // Lint can be a path like `clippy::all`, so it is parsed instead of ident
+        let lints = self
+            .lints
+            .iter()
+            .filter_map(|lint| lint.parse::<proc_macro2::TokenStream>().ok());
+        // Outer attribute on every module instead of inner one, so the code can be `include!`d
+        let allow = match self.lints.is_empty() {
+            true => quote::quote! {},
+            false => quote::quote! { #[allow(#(#lints),*)] },
+        };
         let components_module = self.components.print();
-        let paths_module = self.paths.print();
+        let paths_module = self.paths.print_grouped(&self.api.tag_groups());
 
-        quote::quote! {
-            #![allow(dead_code, unused_imports)]
+        let modules = vec![api_module, client_module, components_module, paths_module]
+            .into_iter()
+            .filter(|module| !module.is_empty());
 
-            #api_module
-            #components_module
-            #paths_module
+        quote::quote! {
+            #(
+                #allow
+                #modules
+            )*
         }
     }
 }
@@ -51,12 +102,14 @@ mod tests {
     use super::{
         api::{ApiModule, ApiStruct, BindApiMethod, HttpMethod, ImplApi},
         components::{
-            parameters::ParametersModule, request_bodies::RequestBodiesModule,
-            responses::ResponsesModule, schemas::SchemasModule, Component, ComponentsModule,
-            EnumVariant, Field, FieldType, FormatFloat, FormatInteger, FormatString, NativeType,
+            module::ComponentsModule, parameters::ParametersModule,
+            request_bodies::RequestBodiesModule, responses::ResponsesModule,
+            schemas::SchemasModule, Component, EnumVariant, Field, FieldType, FormatFloat,
+            FormatInteger, FormatString, NativeType,
         },
         paths::{
-            ContentType, Path, PathsModule, QueryParam, ResponseEnum, ResponseStatus, StatusVariant,
+            module::PathsModule, ContentType, Path, QueryParam, ResponseEnum, ResponseStatus,
+            StatusVariant,
         },
         GeneratedModule,
     };
@@ -69,6 +122,13 @@ mod tests {
         api_name: "ExampleApiDef".to_owned(),
         description: Some("Public API for frontend and OAuth applications [Review Github](https://developer.github.com/apps/building-oauth-apps/authorizing-oauth-apps/)".to_owned()),
         terms_of_service: None,
+        operations: vec![
+            ("/session".to_owned(), HttpMethod::Get),
+            ("/session".to_owned(), HttpMethod::Post),
+            ("/register/confirmation".to_owned(), HttpMethod::Post),
+        ],
+        spec: None,
+        prefix: String::new(),
     };
 
         let m1 = BindApiMethod {
@@ -76,6 +136,9 @@ mod tests {
             name: "sessionGet".to_owned(),
             path: "/session".to_owned(),
             request_body: None,
+            security: vec![],
+            tags: vec!["session".to_owned()],
+            extractors: vec![],
         };
 
         let m2 = BindApiMethod {
@@ -83,6 +146,9 @@ mod tests {
             name: "sessionCreate".to_owned(),
             path: "/session".to_owned(),
             request_body: Some("SessionCreateBody".to_owned()),
+            security: vec![],
+            tags: vec!["session".to_owned()],
+            extractors: vec![],
         };
 
         let m3 = BindApiMethod {
@@ -90,16 +156,22 @@ mod tests {
             name: "registerConfirmation".to_owned(),
             path: "/register/confirmation".to_owned(),
             request_body: Some("RegisterConfirmation".to_owned()),
+            security: vec![],
+            tags: vec![],
+            extractors: vec![],
         };
 
         let methods = ImplApi {
             api_name: api.api_name.clone(),
             methods: vec![m1, m2, m3],
+            security_schemes: vec![],
+            handlers: false,
         };
 
         let api_module = ApiModule {
             structure: api,
             methods,
+            split_by_tag: false,
         };
 
         let components_module = ComponentsModule {
@@ -114,6 +186,7 @@ mod tests {
                     variants: vec![EnumVariant {
                         name: "code".to_owned(),
                         description: None,
+                        x_variant_name: None,
                     }],
                 },
                 Component::Type {
@@ -139,6 +212,8 @@ mod tests {
                             required: true,
                             description: None,
                             field_type: FieldType::Custom("RegisterConfirmationFailedError".to_owned()),
+                            default: None,
+                            skip_serializing: false,
                         }],
                         description: Some("Answer for registration confirmation".to_owned()),
                     },
@@ -148,14 +223,17 @@ mod tests {
                             EnumVariant {
                                 name: "code_invalid_or_expired".to_owned(),
                                 description: None,
+                                x_variant_name: None,
                             },
                             EnumVariant {
                                 name: "email_already_activated".to_owned(),
                                 description: None,
+                                x_variant_name: None,
                             },
                             EnumVariant {
                                 name: "invalid_form".to_owned(),
                                 description: None,
+                                x_variant_name: None,
                             },
                         ],
                         description: None,
@@ -172,6 +250,8 @@ mod tests {
                             field_type: FieldType::Native(NativeType::Integer {
                                 format: FormatInteger::Int64,
                             }),
+                            default: None,
+                            skip_serializing: false,
                         }],
                     },
                 ],
@@ -189,6 +269,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::Email,
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "demo".to_owned(),
@@ -199,6 +281,8 @@ mod tests {
                                         format: FormatString::Email,
                                     },
                                 ))))),
+                                default: None,
+                                skip_serializing: false,
                             },
                         ],
                     },
@@ -213,6 +297,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "firstName".to_owned(),
@@ -221,6 +307,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "lastName".to_owned(),
@@ -229,6 +317,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "password".to_owned(),
@@ -237,6 +327,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "demo".to_owned(),
@@ -245,12 +337,16 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::Float {
                                     format: FormatFloat::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "customizer".to_owned(),
                                 required: false,
                                 description: None,
                                 field_type: FieldType::Internal("crate::app::MySuperType".to_owned()),
+                                default: None,
+                                skip_serializing: false,
                             },
                         ],
                     },
@@ -269,6 +365,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "firstName".to_owned(),
@@ -277,6 +375,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "lastName".to_owned(),
@@ -285,6 +385,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "password".to_owned(),
@@ -293,6 +395,8 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::String {
                                     format: FormatString::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "demo".to_owned(),
@@ -301,22 +405,31 @@ mod tests {
                                 field_type: FieldType::Native(NativeType::Float {
                                     format: FormatFloat::default(),
                                 }),
+                                default: None,
+                                skip_serializing: false,
                             },
                             Field {
                                 name: "customizer".to_owned(),
                                 required: false,
                                 description: None,
                                 field_type: FieldType::Internal("crate::app::MySuperType".to_owned()),
+                                default: None,
+                                skip_serializing: false,
                             },
                         ],
                     },
                 ],
             },
+            security_schemes: Default::default(),
         };
 
         let p1 = Path {
             name: "registerConfirmation".to_owned(),
             query_params: vec![],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
             response: ResponseEnum {
                 responses: vec![
                     StatusVariant {
@@ -325,6 +438,8 @@ mod tests {
                         description: None,
                         content_type: None,
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::BadRequest,
@@ -332,6 +447,8 @@ mod tests {
                         description: None,
                         content_type: Some(ContentType::Json),
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::InternalServerError,
@@ -339,6 +456,8 @@ mod tests {
                         description: None,
                         content_type: Some(ContentType::Json),
                         x_variant_name: Some("Unexpected".to_owned()),
+                        headers: vec![],
+                        cookies: vec![],
                     },
                 ],
             },
@@ -369,6 +488,10 @@ mod tests {
                     required: false,
                 },
             ],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
             response: ResponseEnum {
                 responses: vec![
                     StatusVariant {
@@ -377,6 +500,8 @@ mod tests {
                         description: Some("User logined, cookies writed\nFoo".to_owned()),
                         content_type: None,
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::BadRequest,
@@ -384,6 +509,8 @@ mod tests {
                         description: None,
                         content_type: Some(ContentType::Json),
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::InternalServerError,
@@ -391,6 +518,8 @@ mod tests {
                         description: None,
                         content_type: Some(ContentType::Json),
                         x_variant_name: Some("Unexpected".to_owned()),
+                        headers: vec![],
+                        cookies: vec![],
                     },
                 ],
             },
@@ -402,28 +531,47 @@ mod tests {
 
         let generated_module = GeneratedModule {
             api: api_module,
+            client: None,
             components: components_module,
             paths: paths_module,
+            server: true,
+            lints: vec!["dead_code".to_owned(), "unused_imports".to_owned()],
         };
 
         assert_snapshot!(shot(generated_module), @r###"
-        #![allow(dead_code, unused_imports)]
+        #[allow(dead_code, unused_imports)]
         pub mod api {
-            #[doc = "Public API for frontend and OAuth applications [Review Github](https://developer.github.com/apps/building-oauth-apps/authorizing-oauth-apps/)"]
+            /// Public API for frontend and OAuth applications [Review Github](https://developer.github.com/apps/building-oauth-apps/authorizing-oauth-apps/)
             pub struct ExampleApiDef {
                 api: actix_swagger::Api,
             }
             impl ExampleApiDef {
                 pub fn new() -> Self {
                     Self {
-                        api: actix_swagger::Api::new(),
+                        api: actix_swagger::Api::new()
+                            .operation("/session", actix_swagger::Method::GET)
+                            .operation("/session", actix_swagger::Method::POST)
+                            .operation("/register/confirmation", actix_swagger::Method::POST),
                     }
                 }
+                /// Mount routes under `prefix` instead of path of the server
+                pub fn with_prefix(mut self, prefix: &str) -> Self {
+                    self.api = self.api.with_prefix(prefix);
+                    self
+                }
+                /// Panic on register when some operation has no handler, otherwise it answers 501
+                pub fn strict(mut self, strict: bool) -> Self {
+                    self.api = self.api.strict(strict);
+                    self
+                }
+                /// Fails when some operation has no handler, routes are ambiguous or required security scheme is not authenticated, `register` panics on them
+                pub fn check(&self) -> Result<(), actix_swagger::RegisterError> {
+                    self.api.check()
+                }
             }
             impl Default for ExampleApiDef {
                 fn default() -> Self {
-                    let api = Self::new();
-                    api
+                    Self::new()
                 }
             }
             impl actix_web::dev::HttpServiceFactory for ExampleApiDef {
@@ -431,60 +579,105 @@ mod tests {
                     self.api.register(config);
                 }
             }
-            use super::paths;
-            use actix_swagger::{Answer, Method};
             use actix_web::FromRequest;
+            use actix_swagger::{Answer, Method};
             use std::future::Future;
+            use super::paths;
             impl ExampleApiDef {
                 pub fn bind_session_get<F, T, R>(mut self, handler: F) -> Self
                 where
-                    F: Factory<T, R, Answer<'static, paths::session_get::Response>>,
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::session_get::Response>,
+                        Future = R,
+                    >,
                     T: FromRequest + 'static,
                     R: Future<Output = Answer<'static, paths::session_get::Response>> + 'static,
                 {
-                    self.api = self.api.bind("/session".to_owned(), Method::GET, handler);
+                    self.api = self.api.bind("/session", Method::GET, handler);
                     self
                 }
-                #[doc = "Request body - super::requst_bodies::SessionCreateBody"]
+                /// Request body - super::requst_bodies::SessionCreateBody
                 pub fn bind_session_create<F, T, R>(mut self, handler: F) -> Self
                 where
-                    F: Factory<T, R, Answer<'static, paths::session_create::Response>>,
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::session_create::Response>,
+                        Future = R,
+                    >,
                     T: FromRequest + 'static,
-                    R: Future<Output = Answer<'static, paths::session_create::Response>> + 'static,
+                    R: Future<Output = Answer<'static, paths::session_create::Response>>
+                        + 'static,
                 {
-                    self.api = self.api.bind("/session".to_owned(), Method::POST, handler);
+                    self.api = self.api.bind("/session", Method::POST, handler);
                     self
                 }
-                #[doc = "Request body - super::requst_bodies::RegisterConfirmation"]
+                /// Request body - super::requst_bodies::RegisterConfirmation
                 pub fn bind_register_confirmation<F, T, R>(mut self, handler: F) -> Self
                 where
-                    F: Factory<T, R, Answer<'static, paths::register_confirmation::Response>>,
+                    F: actix_web::Handler<
+                        T,
+                        Output = Answer<'static, paths::register_confirmation::Response>,
+                        Future = R,
+                    >,
                     T: FromRequest + 'static,
-                    R: Future<Output = Answer<'static, paths::register_confirmation::Response>> + 'static,
+                    R: Future<Output = Answer<'static, paths::register_confirmation::Response>>
+                        + 'static,
                 {
-                    self.api = self
-                        .api
-                        .bind("/register/confirmation".to_owned(), Method::POST, handler);
+                    self.api = self.api.bind("/register/confirmation", Method::POST, handler);
                     self
                 }
+                /// Middleware and app data of `GET /session`
+                pub fn route_session_get(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::GET)],
+                    )
+                }
+                /// Middleware and app data of `POST /session`
+                pub fn route_session_create(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::POST)],
+                    )
+                }
+                /// Middleware and app data of `POST /register/confirmation`
+                pub fn route_register_confirmation(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/register/confirmation", Method::POST)],
+                    )
+                }
+                /// Middleware and app data of operations tagged `session`
+                pub fn tag_session(self) -> actix_swagger::Group<Self> {
+                    actix_swagger::Group::new(
+                        self,
+                        |api| &mut api.api,
+                        vec![("/session", Method::GET), ("/session", Method::POST)],
+                    )
+                }
             }
         }
+        #[allow(dead_code, unused_imports)]
         pub mod components {
             pub mod parameters {
-                use serde::{Deserialize, Serialize};
-                #[doc = "response_type is set to code indicating that you want an authorization code as the response."]
+                use serde::{Serialize, Deserialize};
+                /// response_type is set to code indicating that you want an authorization code as the response.
                 #[derive(Debug, Serialize, Deserialize)]
                 pub enum OauthResponseType {
                     #[serde(rename = "code")]
                     Code,
                 }
-                #[doc = "The client_id is the identifier for your app"]
+                /// The client_id is the identifier for your app
                 pub type OauthClientId = uuid::Uuid;
-                #[doc = "redirect_uri may be optional depending on the API, but is highly recommended"]
+                /// redirect_uri may be optional depending on the API, but is highly recommended
                 pub type OauthRedirectUri = String;
             }
             pub mod request_bodies {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
                 #[derive(Debug, Serialize, Deserialize)]
                 pub struct Register {
                     pub email: String,
@@ -504,8 +697,8 @@ mod tests {
                 }
             }
             pub mod responses {
-                use serde::{Deserialize, Serialize};
-                #[doc = "Answer for registration confirmation"]
+                use serde::{Serialize, Deserialize};
+                /// Answer for registration confirmation
                 #[derive(Debug, Serialize, Deserialize)]
                 pub struct RegisterConfirmationFailed {
                     pub error: RegisterConfirmationFailedError,
@@ -519,16 +712,16 @@ mod tests {
                     #[serde(rename = "invalid_form")]
                     InvalidForm,
                 }
-                #[doc = "Registration link sent to email, now user can find out when the link expires"]
+                /// Registration link sent to email, now user can find out when the link expires
                 #[derive(Debug, Serialize, Deserialize)]
                 pub struct RegistrationRequestCreated {
-                    #[doc = "UTC Unix TimeStamp when the link expires"]
+                    /// UTC Unix TimeStamp when the link expires
                     #[serde(rename = "expiresAt")]
                     pub expires_at: i64,
                 }
             }
             pub mod schemas {
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
                 #[derive(Debug, Serialize, Deserialize)]
                 pub struct RegisterConfirmation {
                     #[serde(rename = "confirmationCode")]
@@ -542,13 +735,15 @@ mod tests {
                     pub customizer: Option<crate::app::MySuperType>,
                 }
             }
+            pub mod security_schemes {}
         }
+        #[allow(dead_code, unused_imports)]
         pub mod paths {
             use super::components::{parameters, responses};
             pub mod register_confirmation {
                 use super::responses;
                 use actix_swagger::{Answer, ContentType, StatusCode};
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
                 #[derive(Debug, Serialize)]
                 #[serde(untagged)]
                 pub enum Response {
@@ -571,16 +766,29 @@ mod tests {
                         };
                         Answer::new(self).status(status).content_type(content_type)
                     }
+                    pub fn from_response(
+                        response: &actix_swagger::client::ClientResponse,
+                    ) -> Result<Self, actix_swagger::client::ClientError> {
+                        match response.status {
+                            StatusCode::CREATED => Ok(Self::Created),
+                            StatusCode::BAD_REQUEST => Ok(Self::BadRequest(response.json()?)),
+                            StatusCode::INTERNAL_SERVER_ERROR => Ok(Self::Unexpected),
+                            status => {
+                                Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                            }
+                        }
+                    }
                 }
             }
             pub mod session_create {
                 use super::responses;
                 use actix_swagger::{Answer, ContentType, StatusCode};
-                use serde::{Deserialize, Serialize};
+                use serde::{Serialize, Deserialize};
                 #[derive(Debug, Serialize)]
                 #[serde(untagged)]
                 pub enum Response {
-                    #[doc = "User logined, cookies writed\nFoo"]
+                    /// User logined, cookies writed
+                    /// Foo
                     Created,
                     BadRequest(responses::SessionCreateFailed),
                     Unexpected,
@@ -600,18 +808,30 @@ mod tests {
                         };
                         Answer::new(self).status(status).content_type(content_type)
                     }
+                    pub fn from_response(
+                        response: &actix_swagger::client::ClientResponse,
+                    ) -> Result<Self, actix_swagger::client::ClientError> {
+                        match response.status {
+                            StatusCode::CREATED => Ok(Self::Created),
+                            StatusCode::BAD_REQUEST => Ok(Self::BadRequest(response.json()?)),
+                            StatusCode::INTERNAL_SERVER_ERROR => Ok(Self::Unexpected),
+                            status => {
+                                Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                            }
+                        }
+                    }
                 }
                 use super::parameters;
-                #[derive(Debug, Deserialize)]
+                #[derive(Debug, Serialize, Deserialize)]
                 pub struct QueryParams {
-                    #[doc = "response_type is set to code indicating that you want an authorization code as the response."]
+                    /// response_type is set to code indicating that you want an authorization code as the response.
                     #[serde(rename = "responseType")]
                     pub response_type: parameters::OauthResponseType,
                     pub redirect_uri: Option<parameters::OauthRedirectUri>,
                     #[serde(rename = "GlobalNameOfTheUniverse")]
                     pub global_name_of_the_universe: Option<parameters::OauthClientId>,
                 }
-                pub type Query = actix_web::http::Query<QueryParams>;
+                pub type Query = actix_web::web::Query<QueryParams>;
             }
         }
         "###);
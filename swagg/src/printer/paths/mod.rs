@@ -1,4 +1,3 @@
-pub use module::*;
 pub use path::*;
 pub use response_status::*;
 
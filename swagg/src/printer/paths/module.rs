@@ -1,5 +1,5 @@
 use super::Path;
-use crate::printer::Printable;
+use crate::printer::{api::TagGroup, ident::to_snake_ident, Printable};
 use quote::quote;
 
 #[derive(Default)]
@@ -7,23 +7,56 @@ pub struct PathsModule {
     pub paths: Vec<Path>,
 }
 
-impl Printable for PathsModule {
-    fn print(&self) -> proc_macro2::TokenStream {
-        let paths = self.paths.print();
+impl PathsModule {
+    /// Put paths of every tag group into own module
+    ///
+    /// Modules of the paths are reexported, so `paths::session_get` works for any grouping
+    pub fn print_grouped(&self, groups: &[TagGroup]) -> proc_macro2::TokenStream {
+        let grouped = |path: &&Path| groups.iter().any(|group| group.names.contains(&path.name));
+        let paths = self
+            .paths
+            .iter()
+            .filter(|path| !grouped(path))
+            .map(|path| path.print());
+
+        let modules = groups.iter().map(|group| {
+            let module_name = to_snake_ident(&group.module);
+            let paths = self
+                .paths
+                .iter()
+                .filter(|path| group.names.contains(&path.name))
+                .map(|path| path.print());
+
+            quote! {
+                pub mod #module_name {
+                    use super::{parameters, responses};
+                    #(#paths)*
+                }
+                pub use #module_name::*;
+            }
+        });
 
         quote! {
             pub mod paths {
                 use super::components::{parameters, responses};
-                #paths
+                #(#paths)*
+                #(#modules)*
             }
         }
     }
 }
 
+impl Printable for PathsModule {
+    fn print(&self) -> proc_macro2::TokenStream {
+        self.print_grouped(&[])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::shot;
+    use crate::printer::paths::ResponseEnum;
+    use crate::test::{pretty, shot};
     use insta::assert_snapshot;
 
     #[test]
@@ -34,4 +67,86 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn paths_grouped_by_tag() {
+        let path = |name: &str| Path {
+            name: name.to_owned(),
+            response: ResponseEnum { responses: vec![] },
+            query_params: vec![],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
+        };
+        let module = PathsModule {
+            paths: vec![path("health"), path("session_get")],
+        };
+
+        assert_snapshot!(pretty(module.print_grouped(&[TagGroup {
+            tag: "Session".to_owned(),
+            module: "session".to_owned(),
+            names: vec!["session_get".to_owned()],
+        }]).to_string()), @r###"
+        pub mod paths {
+            use super::components::{parameters, responses};
+            pub mod health {
+                use super::responses;
+                use actix_swagger::{Answer, ContentType, StatusCode};
+                use serde::{Serialize, Deserialize};
+                #[derive(Debug, Serialize)]
+                #[serde(untagged)]
+                pub enum Response {}
+                impl Response {
+                    #[inline]
+                    pub fn to_answer(self) -> Answer<'static, Self> {
+                        let status = match self {};
+                        let content_type = match self {};
+                        Answer::new(self).status(status).content_type(content_type)
+                    }
+                    pub fn from_response(
+                        response: &actix_swagger::client::ClientResponse,
+                    ) -> Result<Self, actix_swagger::client::ClientError> {
+                        match response.status {
+                            status => {
+                                Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                            }
+                        }
+                    }
+                }
+            }
+            pub mod session {
+                use super::{parameters, responses};
+                pub mod session_get {
+                    use super::responses;
+                    use actix_swagger::{Answer, ContentType, StatusCode};
+                    use serde::{Serialize, Deserialize};
+                    #[derive(Debug, Serialize)]
+                    #[serde(untagged)]
+                    pub enum Response {}
+                    impl Response {
+                        #[inline]
+                        pub fn to_answer(self) -> Answer<'static, Self> {
+                            let status = match self {};
+                            let content_type = match self {};
+                            Answer::new(self).status(status).content_type(content_type)
+                        }
+                        pub fn from_response(
+                            response: &actix_swagger::client::ClientResponse,
+                        ) -> Result<Self, actix_swagger::client::ClientError> {
+                            match response.status {
+                                status => {
+                                    Err(
+                                        actix_swagger::client::ClientError::UnexpectedStatus(status),
+                                    )
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            pub use session::*;
+        }
+        "###);
+    }
 }
@@ -1,5 +1,9 @@
 use super::ResponseStatus;
-use crate::printer::Printable;
+use crate::printer::{
+    components::FieldType,
+    ident::{to_pascal_ident, to_snake_ident, unraw},
+    print_doc, Printable,
+};
 use inflections::Inflect;
 use quote::{format_ident, quote};
 
@@ -7,6 +11,14 @@ pub struct Path {
     pub name: String,
     pub response: ResponseEnum,
     pub query_params: Vec<QueryParam>,
+    pub path_params: Vec<PathParam>,
+
+    /// Names are matched in lowercase, the same way actix-web stores them
+    pub header_params: Vec<HeaderParam>,
+    pub cookie_params: Vec<CookieParam>,
+
+    /// Print `from_response` decoding the response, generated client uses it
+    pub client: bool,
 }
 
 impl Path {
@@ -50,6 +62,62 @@ impl Path {
         }
     }
 
+    fn has_headers(&self) -> bool {
+        self.response.responses.iter().any(|r| r.has_headers())
+    }
+
+    fn print_headers_variants(&self) -> proc_macro2::TokenStream {
+        let variants = self
+            .response
+            .responses
+            .iter()
+            .map(|r| r.print_headers_variant());
+        let tokens = quote! { #(#variants,)* };
+
+        quote! {
+            match &self {
+                #tokens
+            }
+        }
+    }
+
+    fn print_from_response_variants(&self) -> proc_macro2::TokenStream {
+        let variants = self
+            .response
+            .responses
+            .iter()
+            .map(|r| r.print_from_response_variant());
+
+        quote! {
+            match response.status {
+                #(#variants,)*
+                status => Err(actix_swagger::client::ClientError::UnexpectedStatus(status)),
+            }
+        }
+    }
+
+    fn print_headers_structs(&self) -> proc_macro2::TokenStream {
+        let structs = self
+            .response
+            .responses
+            .iter()
+            .map(|r| r.print_headers_struct(self.client));
+
+        quote! { #(#structs)* }
+    }
+
+    fn parameters_use(&self) -> proc_macro2::TokenStream {
+        let no_params = self.query_params.is_empty()
+            && self.path_params.is_empty()
+            && self.header_params.is_empty()
+            && self.cookie_params.is_empty();
+
+        match no_params {
+            true => quote! {},
+            false => quote! { use super::parameters; },
+        }
+    }
+
     fn query_params_impl(&self) -> proc_macro2::TokenStream {
         if self.query_params.is_empty() {
             quote! {}
@@ -57,14 +125,63 @@ impl Path {
             let query_params = self.query_params.print();
 
             quote! {
-                use super::parameters;
-
-                #[derive(Debug, Deserialize)]
+                #[derive(Debug, Serialize, Deserialize)]
                 pub struct QueryParams {
                     #query_params
                 }
 
-                pub type Query = actix_web::http::Query<QueryParams>;
+                pub type Query = actix_web::web::Query<QueryParams>;
+            }
+        }
+    }
+
+    fn path_params_impl(&self) -> proc_macro2::TokenStream {
+        if self.path_params.is_empty() {
+            quote! {}
+        } else {
+            let path_params = self.path_params.print();
+
+            quote! {
+                #[derive(Debug, Serialize, Deserialize)]
+                pub struct PathParams {
+                    #path_params
+                }
+
+                pub type Path = actix_web::web::Path<PathParams>;
+            }
+        }
+    }
+
+    fn header_params_impl(&self) -> proc_macro2::TokenStream {
+        if self.header_params.is_empty() {
+            quote! {}
+        } else {
+            let header_params = self.header_params.print();
+
+            quote! {
+                #[derive(Debug, Serialize, Deserialize)]
+                pub struct HeaderParams {
+                    #header_params
+                }
+
+                pub type Headers = actix_swagger::Headers<HeaderParams>;
+            }
+        }
+    }
+
+    fn cookie_params_impl(&self) -> proc_macro2::TokenStream {
+        if self.cookie_params.is_empty() {
+            quote! {}
+        } else {
+            let cookie_params = self.cookie_params.print();
+
+            quote! {
+                #[derive(Debug, Serialize, Deserialize)]
+                pub struct CookieParams {
+                    #cookie_params
+                }
+
+                pub type Cookies = actix_swagger::Cookies<CookieParams>;
             }
         }
     }
@@ -72,11 +189,40 @@ impl Path {
 
 impl Printable for Path {
     fn print(&self) -> proc_macro2::TokenStream {
-        let module_name = format_ident!("{}", self.name.to_snake_case());
+        let module_name = to_snake_ident(&self.name);
         let enum_variants = self.print_enum_variants();
         let status_match = self.print_status_variants();
         let content_type_match = self.print_content_type_variants();
+        let (headers_match, headers_apply) = match self.has_headers() {
+            true => {
+                let headers_match = self.print_headers_variants();
+                (
+                    quote! { let headers = #headers_match; },
+                    quote! { .append_headers(headers) },
+                )
+            }
+            false => (quote! {}, quote! {}),
+        };
+        let from_response = match self.client {
+            true => {
+                let from_response_match = self.print_from_response_variants();
+
+                quote! {
+                    pub fn from_response(
+                        response: &actix_swagger::client::ClientResponse,
+                    ) -> Result<Self, actix_swagger::client::ClientError> {
+                        #from_response_match
+                    }
+                }
+            }
+            false => quote! {},
+        };
+        let headers_structs = self.print_headers_structs();
+        let parameters_use = self.parameters_use();
         let query_params = self.query_params_impl();
+        let path_params = self.path_params_impl();
+        let header_params = self.header_params_impl();
+        let cookie_params = self.cookie_params_impl();
 
         quote! {
             pub mod #module_name {
@@ -95,12 +241,21 @@ impl Printable for Path {
                     pub fn to_answer(self) -> Answer<'static, Self> {
                         let status = #status_match;
                         let content_type = #content_type_match;
+                        #headers_match
 
-                        Answer::new(self).status(status).content_type(content_type)
+                        Answer::new(self).status(status).content_type(content_type)#headers_apply
                     }
+
+                    #from_response
                 }
 
+                #headers_structs
+
+                #parameters_use
                 #query_params
+                #path_params
+                #header_params
+                #cookie_params
             }
         }
 
@@ -133,6 +288,12 @@ pub struct StatusVariant {
 
     /// Variant can be renamed with `x-variant-name`
     pub x_variant_name: Option<String>,
+
+    /// Headers declared in response, carried by variant in `<Variant>Headers` struct
+    pub headers: Vec<ResponseHeader>,
+
+    /// Cookies set by response, carried in the same `<Variant>Headers` struct
+    pub cookies: Vec<ResponseCookie>,
 }
 
 impl StatusVariant {
@@ -141,12 +302,12 @@ impl StatusVariant {
             .x_variant_name
             .clone()
             .unwrap_or(self.status.to_string());
-        format_ident!("{}", name.to_pascal_case())
+        to_pascal_ident(&name)
     }
 
     pub fn description(&self) -> proc_macro2::TokenStream {
         match &self.description {
-            Some(text) => quote! { #[doc = #text] },
+            Some(text) => print_doc(text),
             None => quote! {},
         }
     }
@@ -161,42 +322,319 @@ impl StatusVariant {
         }
     }
 
+    pub fn has_headers(&self) -> bool {
+        !self.headers.is_empty() || !self.cookies.is_empty()
+    }
+
+    pub fn headers_name(&self) -> proc_macro2::Ident {
+        format_ident!("{}Headers", self.name())
+    }
+
+    /// Pattern to match variant without binding its fields
+    fn print_pattern(&self) -> proc_macro2::TokenStream {
+        let variant_name = self.name();
+
+        match (&self.response_type_name, !self.has_headers()) {
+            (Some(_), true) => quote! { Self::#variant_name(_) },
+            (Some(_), false) => quote! { Self::#variant_name(_, _) },
+            (None, false) => quote! { Self::#variant_name(_) },
+            (None, true) => quote! { Self::#variant_name },
+        }
+    }
+
     pub fn print_enum_variant(&self) -> proc_macro2::TokenStream {
         let description = self.description();
         let variant_name = self.name();
+        let headers_name = self.headers_name();
 
-        if let Some(response) = self.response_type_name.clone() {
-            let response_name = format_ident!("{}", response.to_pascal_case());
+        match (self.response_type_name.clone(), !self.has_headers()) {
+            (Some(response), true) => {
+                let response_name = to_pascal_ident(&response);
 
-            quote! {
-                #description
-                #variant_name(responses::#response_name)
+                quote! {
+                    #description
+                    #variant_name(responses::#response_name)
+                }
             }
-        } else {
-            quote! {
+            (Some(response), false) => {
+                let response_name = to_pascal_ident(&response);
+
+                quote! {
+                    #description
+                    #[serde(serialize_with = "actix_swagger::serialize_body")]
+                    #variant_name(responses::#response_name, #headers_name)
+                }
+            }
+            (None, false) => quote! {
+                #description
+                #[serde(serialize_with = "actix_swagger::serialize_empty")]
+                #variant_name(#headers_name)
+            },
+            (None, true) => quote! {
                 #description
                 #variant_name
-            }
+            },
         }
     }
 
     pub fn print_status_variant(&self) -> proc_macro2::TokenStream {
-        let variant_name = self.name();
+        let pattern = self.print_pattern();
         let status = format_ident!("{}", self.status.to_string().to_constant_case());
 
-        match self.response_type_name {
-            Some(_) => quote! { Self::#variant_name(_) => StatusCode::#status },
-            None => quote! { Self::#variant_name => StatusCode::#status },
-        }
+        quote! { #pattern => StatusCode::#status }
     }
 
     pub fn print_content_type_variant(&self) -> proc_macro2::TokenStream {
-        let variant_name = self.name();
+        let pattern = self.print_pattern();
         let content_type = self.content_type();
 
-        match self.response_type_name {
-            Some(_) => quote! { Self::#variant_name(_) => #content_type },
-            None => quote! { Self::#variant_name => #content_type },
+        quote! { #pattern => #content_type }
+    }
+
+    pub fn print_from_response_variant(&self) -> proc_macro2::TokenStream {
+        let variant_name = self.name();
+        let headers_name = self.headers_name();
+        let status = format_ident!("{}", self.status.to_string().to_constant_case());
+
+        let variant = match (&self.response_type_name, !self.has_headers()) {
+            (Some(_), true) => quote! { Self::#variant_name(response.json()?) },
+            (Some(_), false) => quote! {
+                Self::#variant_name(response.json()?, #headers_name::from_response(response)?)
+            },
+            (None, false) => {
+                quote! { Self::#variant_name(#headers_name::from_response(response)?) }
+            }
+            (None, true) => quote! { Self::#variant_name },
+        };
+
+        quote! { StatusCode::#status => Ok(#variant) }
+    }
+
+    pub fn print_headers_variant(&self) -> proc_macro2::TokenStream {
+        let variant_name = self.name();
+
+        match (&self.response_type_name, !self.has_headers()) {
+            (Some(_), false) => quote! { Self::#variant_name(_, headers) => headers.to_pairs() },
+            (None, false) => quote! { Self::#variant_name(headers) => headers.to_pairs() },
+            _ => {
+                let pattern = self.print_pattern();
+                quote! { #pattern => vec![] }
+            }
+        }
+    }
+
+    /// Struct with headers of the variant, `from_response` is printed for the client only
+    pub fn print_headers_struct(&self, client: bool) -> proc_macro2::TokenStream {
+        if !self.has_headers() {
+            return quote! {};
+        }
+
+        let headers_name = self.headers_name();
+        let fields = self.headers.print();
+        let cookie_fields = self.cookies.print();
+        let pairs = self.headers.iter().map(|h| h.print_pair());
+        let cookie_pairs = self.cookies.iter().map(|c| c.print_pair());
+        let cookie_helpers = self.cookies.iter().map(|c| c.print_helper());
+        let from_response = match client {
+            true => {
+                let decoders = self.headers.iter().map(|h| h.print_decode());
+                let cookie_decoders = self.cookies.iter().map(|c| c.print_decode());
+
+                quote! {
+                    pub fn from_response(
+                        response: &actix_swagger::client::ClientResponse,
+                    ) -> Result<Self, actix_swagger::client::ClientError> {
+                        Ok(Self {
+                            #(#decoders)*
+                            #(#cookie_decoders)*
+                        })
+                    }
+                }
+            }
+            false => quote! {},
+        };
+
+        quote! {
+            #[derive(Debug)]
+            pub struct #headers_name {
+                #fields
+                #cookie_fields
+            }
+
+            impl #headers_name {
+                #(#cookie_helpers)*
+
+                pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                    let mut pairs = vec![];
+                    #(#pairs)*
+                    #(#cookie_pairs)*
+                    pairs
+                }
+
+                #from_response
+            }
+        }
+    }
+}
+
+/// Header declared in response `headers`
+pub struct ResponseHeader {
+    /// Name of the header as it sent, field name will be converted to snake_case
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub required: bool,
+
+    pub field_type: FieldType,
+}
+
+impl ResponseHeader {
+    fn field_ident(&self) -> proc_macro2::Ident {
+        to_snake_ident(&self.name)
+    }
+
+    /// Push header value to `pairs` if it is set
+    fn print_pair(&self) -> proc_macro2::TokenStream {
+        let name = self.name.clone();
+        let field = self.field_ident();
+        let to_value = match self.field_type {
+            FieldType::Array(_) => quote! {
+                value.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+            },
+            _ => quote! { value.to_string() },
+        };
+
+        match self.required {
+            true => quote! {
+                let value = &self.#field;
+                pairs.push((#name, #to_value));
+            },
+            false => quote! {
+                if let Some(value) = &self.#field {
+                    pairs.push((#name, #to_value));
+                }
+            },
+        }
+    }
+
+    /// Read field from response received by generated client
+    fn print_decode(&self) -> proc_macro2::TokenStream {
+        let name = self.name.clone();
+        let field = self.field_ident();
+        let read = match self.field_type {
+            FieldType::Array(_) => quote! { response.header_list(#name)? },
+            _ => quote! { response.header(#name)? },
+        };
+
+        match self.required {
+            true => quote! {
+                #field: #read.ok_or(actix_swagger::client::ClientError::MissingHeader(#name))?,
+            },
+            false => quote! { #field: #read, },
+        }
+    }
+}
+
+impl Printable for ResponseHeader {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let name_ident = self.field_ident();
+        let description = match &self.description {
+            Some(description) => print_doc(description),
+            None => quote! {},
+        };
+        let type_stream = self.field_type.print();
+        let type_value = match self.required {
+            true => type_stream,
+            false => quote! { Option<#type_stream> },
+        };
+
+        quote! {
+            #description
+            pub #name_ident: #type_value,
+        }
+    }
+}
+
+/// Cookie set by response with `Set-Cookie` header
+pub struct ResponseCookie {
+    /// Name of the cookie, field name will be converted to snake_case
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub required: bool,
+}
+
+impl ResponseCookie {
+    fn field_ident(&self) -> proc_macro2::Ident {
+        to_snake_ident(&self.name)
+    }
+
+    /// Push `Set-Cookie` header to `pairs` if cookie is set
+    fn print_pair(&self) -> proc_macro2::TokenStream {
+        let field = self.field_ident();
+
+        match self.required {
+            true => quote! {
+                pairs.push(("Set-Cookie", self.#field.to_string()));
+            },
+            false => quote! {
+                if let Some(cookie) = &self.#field {
+                    pairs.push(("Set-Cookie", cookie.to_string()));
+                }
+            },
+        }
+    }
+
+    /// Constructor of the cookie with documented name
+    fn print_helper(&self) -> proc_macro2::TokenStream {
+        let name = self.name.clone();
+        let helper = format_ident!("{}_cookie", unraw(&self.field_ident()));
+        let doc = print_doc(&format!("Create `{}` cookie", name));
+
+        quote! {
+            #doc
+            pub fn #helper<V>(value: V) -> actix_swagger::Cookie<'static>
+            where
+                V: Into<std::borrow::Cow<'static, str>>,
+            {
+                actix_swagger::Cookie::new(#name, value)
+            }
+        }
+    }
+
+    /// Read cookie from response received by generated client
+    fn print_decode(&self) -> proc_macro2::TokenStream {
+        let name = self.name.clone();
+        let field = self.field_ident();
+
+        match self.required {
+            true => quote! {
+                #field: response
+                    .cookie(#name)
+                    .ok_or(actix_swagger::client::ClientError::MissingCookie(#name))?,
+            },
+            false => quote! { #field: response.cookie(#name), },
+        }
+    }
+}
+
+impl Printable for ResponseCookie {
+    fn print(&self) -> proc_macro2::TokenStream {
+        let name_ident = self.field_ident();
+        let description = match &self.description {
+            Some(description) => print_doc(description),
+            None => quote! {},
+        };
+        let type_value = match self.required {
+            true => quote! { actix_swagger::Cookie<'static> },
+            false => quote! { Option<actix_swagger::Cookie<'static>> },
+        };
+
+        quote! {
+            #description
+            pub #name_ident: #type_value,
         }
     }
 }
@@ -206,12 +644,11 @@ pub enum ContentType {
     Json,
 }
 
-impl ToString for ContentType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ContentType::Json => "Json",
+            ContentType::Json => write!(f, "Json"),
         }
-        .to_owned()
     }
 }
 
@@ -236,19 +673,27 @@ pub struct QueryParam {
     pub required: bool,
 }
 
+/// Parameter with `in: path`, printed the same way as query one
+pub type PathParam = QueryParam;
+
+/// Parameter with `in: header`, printed the same way as query one
+pub type HeaderParam = QueryParam;
+
+/// Parameter with `in: cookie`, printed the same way as query one
+pub type CookieParam = QueryParam;
+
 impl Printable for QueryParam {
     fn print(&self) -> proc_macro2::TokenStream {
         let name_original = self.name.clone();
-        let name_snake = name_original.to_snake_case();
-        let name_ident = format_ident!("{}", name_snake);
-        let rename = match name_snake != name_original {
+        let name_ident = to_snake_ident(&name_original);
+        let rename = match unraw(&name_ident) != name_original {
             true => quote! { #[serde(rename = #name_original)] },
             false => quote! {},
         };
 
-        let type_name = format_ident!("{}", self.type_ref.to_pascal_case());
+        let type_name = to_pascal_ident(&self.type_ref);
         let description = match &self.description {
-            Some(description) => quote! { #[doc = #description]},
+            Some(description) => print_doc(description),
             None => quote! {},
         };
 
@@ -278,12 +723,16 @@ mod tests {
             response: ResponseEnum {
                 responses: vec![]
             },
-            query_params: vec![]
+            query_params: vec![],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
         }), @r###"
         pub mod example {
             use super::responses;
             use actix_swagger::{Answer, ContentType, StatusCode};
-            use serde::{Deserialize, Serialize};
+            use serde::{Serialize, Deserialize};
             #[derive(Debug, Serialize)]
             #[serde(untagged)]
             pub enum Response {}
@@ -294,6 +743,15 @@ mod tests {
                     let content_type = match self {};
                     Answer::new(self).status(status).content_type(content_type)
                 }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
             }
         }
         "###);
@@ -311,6 +769,8 @@ mod tests {
                         response_type_name: None,
                         description: None,
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::Created,
@@ -318,6 +778,8 @@ mod tests {
                         response_type_name: None,
                         description: None,
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::Accepted,
@@ -325,6 +787,8 @@ mod tests {
                         response_type_name: Some("unexpected_FRIEND_Name".to_owned()),
                         description: None,
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::BadRequest,
@@ -332,6 +796,8 @@ mod tests {
                         response_type_name: Some("unexpected_FRIEND_Name".to_owned()),
                         description: Some("My super simple description.\nAnother back".to_owned()),
                         x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::InternalServerError,
@@ -339,6 +805,8 @@ mod tests {
                         response_type_name: Some("unexpected_FRIEND_Name".to_owned()),
                         description: Some("My super simple description.\nAnother back".to_owned()),
                         x_variant_name: Some("Unexpected".to_owned()),
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::ExpectationFailed,
@@ -346,6 +814,8 @@ mod tests {
                         response_type_name: Some("unexpected_FRIEND_Name".to_owned()),
                         description: Some("My super simple description.\nAnother back".to_owned()),
                         x_variant_name: Some("Expectation".to_owned()),
+                        headers: vec![],
+                        cookies: vec![],
                     },
                     StatusVariant {
                         status: ResponseStatus::NotFound,
@@ -353,28 +823,38 @@ mod tests {
                         response_type_name: None,
                         description: Some("My super simple description.\nAnother back".to_owned()),
                         x_variant_name: Some("No".to_owned()),
+                        headers: vec![],
+                        cookies: vec![],
                     },
                 ]
             },
-            query_params: vec![]
+            query_params: vec![],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
         }), @r###"
         pub mod example {
             use super::responses;
             use actix_swagger::{Answer, ContentType, StatusCode};
-            use serde::{Deserialize, Serialize};
+            use serde::{Serialize, Deserialize};
             #[derive(Debug, Serialize)]
             #[serde(untagged)]
             pub enum Response {
                 Ok,
                 Created,
                 Accepted(responses::UnexpectedFriendName),
-                #[doc = "My super simple description.\nAnother back"]
+                /// My super simple description.
+                /// Another back
                 BadRequest(responses::UnexpectedFriendName),
-                #[doc = "My super simple description.\nAnother back"]
+                /// My super simple description.
+                /// Another back
                 Unexpected(responses::UnexpectedFriendName),
-                #[doc = "My super simple description.\nAnother back"]
+                /// My super simple description.
+                /// Another back
                 Expectation(responses::UnexpectedFriendName),
-                #[doc = "My super simple description.\nAnother back"]
+                /// My super simple description.
+                /// Another back
                 No,
             }
             impl Response {
@@ -400,7 +880,351 @@ mod tests {
                     };
                     Answer::new(self).status(status).content_type(content_type)
                 }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        StatusCode::OK => Ok(Self::Ok),
+                        StatusCode::CREATED => Ok(Self::Created),
+                        StatusCode::ACCEPTED => Ok(Self::Accepted(response.json()?)),
+                        StatusCode::BAD_REQUEST => Ok(Self::BadRequest(response.json()?)),
+                        StatusCode::INTERNAL_SERVER_ERROR => {
+                            Ok(Self::Unexpected(response.json()?))
+                        }
+                        StatusCode::EXPECTATION_FAILED => Ok(Self::Expectation(response.json()?)),
+                        StatusCode::NOT_FOUND => Ok(Self::No),
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn path_with_response_headers() {
+        use crate::printer::components::{FormatInteger, NativeType};
+
+        assert_snapshot!(shot(Path {
+            name: "Example".to_owned(),
+            response: ResponseEnum {
+                responses: vec![
+                    StatusVariant {
+                        status: ResponseStatus::Ok,
+                        content_type: Some(ContentType::Json),
+                        response_type_name: Some("ExampleList".to_owned()),
+                        description: None,
+                        x_variant_name: None,
+                        headers: vec![
+                            ResponseHeader {
+                                name: "X-RateLimit-Remaining".to_owned(),
+                                description: Some("Requests left in current window".to_owned()),
+                                required: true,
+                                field_type: FieldType::Native(NativeType::Integer { format: FormatInteger::Int32 }),
+                            },
+                            ResponseHeader {
+                                name: "Link".to_owned(),
+                                description: None,
+                                required: false,
+                                field_type: FieldType::Array(Box::new(FieldType::Native(NativeType::String { format: Default::default() }))),
+                            },
+                        ],
+                        cookies: vec![],
+                    },
+                    StatusVariant {
+                        status: ResponseStatus::SeeOther,
+                        content_type: None,
+                        response_type_name: None,
+                        description: None,
+                        x_variant_name: None,
+                        headers: vec![ResponseHeader {
+                            name: "Location".to_owned(),
+                            description: None,
+                            required: true,
+                            field_type: FieldType::Native(NativeType::String { format: Default::default() }),
+                        }],
+                        cookies: vec![],
+                    },
+                    StatusVariant {
+                        status: ResponseStatus::NotFound,
+                        content_type: None,
+                        response_type_name: None,
+                        description: None,
+                        x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![],
+                    },
+                ]
+            },
+            query_params: vec![],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
+        }), @r###"
+        pub mod example {
+            use super::responses;
+            use actix_swagger::{Answer, ContentType, StatusCode};
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize)]
+            #[serde(untagged)]
+            pub enum Response {
+                #[serde(serialize_with = "actix_swagger::serialize_body")]
+                Ok(responses::ExampleList, OkHeaders),
+                #[serde(serialize_with = "actix_swagger::serialize_empty")]
+                SeeOther(SeeOtherHeaders),
+                NotFound,
+            }
+            impl Response {
+                #[inline]
+                pub fn to_answer(self) -> Answer<'static, Self> {
+                    let status = match self {
+                        Self::Ok(_, _) => StatusCode::OK,
+                        Self::SeeOther(_) => StatusCode::SEE_OTHER,
+                        Self::NotFound => StatusCode::NOT_FOUND,
+                    };
+                    let content_type = match self {
+                        Self::Ok(_, _) => Some(ContentType::Json),
+                        Self::SeeOther(_) => None,
+                        Self::NotFound => None,
+                    };
+                    let headers = match &self {
+                        Self::Ok(_, headers) => headers.to_pairs(),
+                        Self::SeeOther(headers) => headers.to_pairs(),
+                        Self::NotFound => vec![],
+                    };
+                    Answer::new(self)
+                        .status(status)
+                        .content_type(content_type)
+                        .append_headers(headers)
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        StatusCode::OK => {
+                            Ok(Self::Ok(response.json()?, OkHeaders::from_response(response)?))
+                        }
+                        StatusCode::SEE_OTHER => {
+                            Ok(Self::SeeOther(SeeOtherHeaders::from_response(response)?))
+                        }
+                        StatusCode::NOT_FOUND => Ok(Self::NotFound),
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
+            }
+            #[derive(Debug)]
+            pub struct OkHeaders {
+                /// Requests left in current window
+                pub x_rate_limit_remaining: i32,
+                pub link: Option<Vec<String>>,
+            }
+            impl OkHeaders {
+                pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                    let mut pairs = vec![];
+                    let value = &self.x_rate_limit_remaining;
+                    pairs.push(("X-RateLimit-Remaining", value.to_string()));
+                    if let Some(value) = &self.link {
+                        pairs
+                            .push((
+                                "Link",
+                                value
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(","),
+                            ));
+                    }
+                    pairs
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    Ok(Self {
+                        x_rate_limit_remaining: response
+                            .header("X-RateLimit-Remaining")?
+                            .ok_or(
+                                actix_swagger::client::ClientError::MissingHeader(
+                                    "X-RateLimit-Remaining",
+                                ),
+                            )?,
+                        link: response.header_list("Link")?,
+                    })
+                }
+            }
+            #[derive(Debug)]
+            pub struct SeeOtherHeaders {
+                pub location: String,
+            }
+            impl SeeOtherHeaders {
+                pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                    let mut pairs = vec![];
+                    let value = &self.location;
+                    pairs.push(("Location", value.to_string()));
+                    pairs
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    Ok(Self {
+                        location: response
+                            .header("Location")?
+                            .ok_or(
+                                actix_swagger::client::ClientError::MissingHeader("Location"),
+                            )?,
+                    })
+                }
+            }
+        }
+        "###);
+    }
+
+    #[test]
+    fn path_with_cookies() {
+        assert_snapshot!(shot(Path {
+            name: "Example".to_owned(),
+            response: ResponseEnum {
+                responses: vec![
+                    StatusVariant {
+                        status: ResponseStatus::Created,
+                        content_type: None,
+                        response_type_name: None,
+                        description: None,
+                        x_variant_name: None,
+                        headers: vec![],
+                        cookies: vec![
+                            ResponseCookie {
+                                name: "session_id".to_owned(),
+                                description: Some("Session token".to_owned()),
+                                required: true,
+                            },
+                            ResponseCookie {
+                                name: "remember-me".to_owned(),
+                                description: None,
+                                required: false,
+                            },
+                        ],
+                    },
+                ]
+            },
+            query_params: vec![
+                QueryParam {
+                    name: "page".to_owned(),
+                    description: None,
+                    required: false,
+                    type_ref: "Page".to_owned()
+                },
+            ],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![
+                CookieParam {
+                    name: "session_id".to_owned(),
+                    description: Some("Session token".to_owned()),
+                    required: true,
+                    type_ref: "SessionId".to_owned()
+                },
+            ],
+            client: true,
+        }), @r###"
+        pub mod example {
+            use super::responses;
+            use actix_swagger::{Answer, ContentType, StatusCode};
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize)]
+            #[serde(untagged)]
+            pub enum Response {
+                #[serde(serialize_with = "actix_swagger::serialize_empty")]
+                Created(CreatedHeaders),
+            }
+            impl Response {
+                #[inline]
+                pub fn to_answer(self) -> Answer<'static, Self> {
+                    let status = match self {
+                        Self::Created(_) => StatusCode::CREATED,
+                    };
+                    let content_type = match self {
+                        Self::Created(_) => None,
+                    };
+                    let headers = match &self {
+                        Self::Created(headers) => headers.to_pairs(),
+                    };
+                    Answer::new(self)
+                        .status(status)
+                        .content_type(content_type)
+                        .append_headers(headers)
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        StatusCode::CREATED => {
+                            Ok(Self::Created(CreatedHeaders::from_response(response)?))
+                        }
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
             }
+            #[derive(Debug)]
+            pub struct CreatedHeaders {
+                /// Session token
+                pub session_id: actix_swagger::Cookie<'static>,
+                pub remember_me: Option<actix_swagger::Cookie<'static>>,
+            }
+            impl CreatedHeaders {
+                /// Create `session_id` cookie
+                pub fn session_id_cookie<V>(value: V) -> actix_swagger::Cookie<'static>
+                where
+                    V: Into<std::borrow::Cow<'static, str>>,
+                {
+                    actix_swagger::Cookie::new("session_id", value)
+                }
+                /// Create `remember-me` cookie
+                pub fn remember_me_cookie<V>(value: V) -> actix_swagger::Cookie<'static>
+                where
+                    V: Into<std::borrow::Cow<'static, str>>,
+                {
+                    actix_swagger::Cookie::new("remember-me", value)
+                }
+                pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+                    let mut pairs = vec![];
+                    pairs.push(("Set-Cookie", self.session_id.to_string()));
+                    if let Some(cookie) = &self.remember_me {
+                        pairs.push(("Set-Cookie", cookie.to_string()));
+                    }
+                    pairs
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    Ok(Self {
+                        session_id: response
+                            .cookie("session_id")
+                            .ok_or(
+                                actix_swagger::client::ClientError::MissingCookie("session_id"),
+                            )?,
+                        remember_me: response.cookie("remember-me"),
+                    })
+                }
+            }
+            use super::parameters;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct QueryParams {
+                pub page: Option<parameters::Page>,
+            }
+            pub type Query = actix_web::web::Query<QueryParams>;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct CookieParams {
+                /// Session token
+                pub session_id: parameters::SessionId,
+            }
+            pub type Cookies = actix_swagger::Cookies<CookieParams>;
         }
         "###);
     }
@@ -431,12 +1255,16 @@ mod tests {
                     required: true,
                     type_ref: "Another".to_owned()
                 },
-            ]
+            ],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
         }), @r###"
         pub mod example {
             use super::responses;
             use actix_swagger::{Answer, ContentType, StatusCode};
-            use serde::{Deserialize, Serialize};
+            use serde::{Serialize, Deserialize};
             #[derive(Debug, Serialize)]
             #[serde(untagged)]
             pub enum Response {}
@@ -447,19 +1275,141 @@ mod tests {
                     let content_type = match self {};
                     Answer::new(self).status(status).content_type(content_type)
                 }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
             }
             use super::parameters;
-            #[derive(Debug, Deserialize)]
+            #[derive(Debug, Serialize, Deserialize)]
             pub struct QueryParams {
                 #[serde(rename = "simple_LONG_DescriptionFor-Me")]
-                pub simple_long_description_for_me: Option<parameters::SimpleLongDescriptionForMe>,
-                #[doc = "This is the description"]
+                pub simple_long_description_for_me: Option<
+                    parameters::SimpleLongDescriptionForMe,
+                >,
+                /// This is the description
                 #[serde(rename = "ARE_YOU_SURE")]
                 pub are_you_sure: Option<parameters::SimpleLongDescriptionForMe>,
                 #[serde(rename = "just-required")]
                 pub just_required: parameters::Another,
             }
-            pub type Query = actix_web::http::Query<QueryParams>;
+            pub type Query = actix_web::web::Query<QueryParams>;
+        }
+        "###);
+    }
+
+    #[test]
+    fn path_with_keyword_names() {
+        assert_snapshot!(shot(Path {
+            name: "match".to_owned(),
+            response: ResponseEnum {
+                responses: vec![]
+            },
+            query_params: vec![
+                QueryParam {
+                    name: "type".to_owned(),
+                    description: None,
+                    required: true,
+                    type_ref: "type".to_owned()
+                },
+                QueryParam {
+                    name: "2fa".to_owned(),
+                    description: None,
+                    required: false,
+                    type_ref: "2fa".to_owned()
+                },
+            ],
+            path_params: vec![],
+            header_params: vec![],
+            cookie_params: vec![],
+            client: true,
+        }), @r###"
+        pub mod r#match {
+            use super::responses;
+            use actix_swagger::{Answer, ContentType, StatusCode};
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize)]
+            #[serde(untagged)]
+            pub enum Response {}
+            impl Response {
+                #[inline]
+                pub fn to_answer(self) -> Answer<'static, Self> {
+                    let status = match self {};
+                    let content_type = match self {};
+                    Answer::new(self).status(status).content_type(content_type)
+                }
+                pub fn from_response(
+                    response: &actix_swagger::client::ClientResponse,
+                ) -> Result<Self, actix_swagger::client::ClientError> {
+                    match response.status {
+                        status => {
+                            Err(actix_swagger::client::ClientError::UnexpectedStatus(status))
+                        }
+                    }
+                }
+            }
+            use super::parameters;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct QueryParams {
+                pub r#type: parameters::Type,
+                #[serde(rename = "2fa")]
+                pub _2fa: Option<parameters::Value2fa>,
+            }
+            pub type Query = actix_web::web::Query<QueryParams>;
+        }
+        "###);
+    }
+
+    #[test]
+    fn path_with_path_and_header_params() {
+        let param = |name: &str, type_ref: &str| QueryParam {
+            name: name.to_owned(),
+            description: None,
+            required: true,
+            type_ref: type_ref.to_owned(),
+        };
+
+        assert_snapshot!(shot(Path {
+            name: "userGet".to_owned(),
+            response: ResponseEnum { responses: vec![] },
+            query_params: vec![],
+            path_params: vec![param("id", "UserId")],
+            header_params: vec![param("x-request-id", "RequestId")],
+            cookie_params: vec![],
+            client: false,
+        }), @r###"
+        pub mod user_get {
+            use super::responses;
+            use actix_swagger::{Answer, ContentType, StatusCode};
+            use serde::{Serialize, Deserialize};
+            #[derive(Debug, Serialize)]
+            #[serde(untagged)]
+            pub enum Response {}
+            impl Response {
+                #[inline]
+                pub fn to_answer(self) -> Answer<'static, Self> {
+                    let status = match self {};
+                    let content_type = match self {};
+                    Answer::new(self).status(status).content_type(content_type)
+                }
+            }
+            use super::parameters;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct PathParams {
+                pub id: parameters::UserId,
+            }
+            pub type Path = actix_web::web::Path<PathParams>;
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct HeaderParams {
+                #[serde(rename = "x-request-id")]
+                pub x_request_id: parameters::RequestId,
+            }
+            pub type Headers = actix_swagger::Headers<HeaderParams>;
         }
         "###);
     }
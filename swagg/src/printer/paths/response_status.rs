@@ -62,8 +62,9 @@ pub enum ResponseStatus {
 }
 
 impl ResponseStatus {
-    pub fn from_code(code: u16) -> ResponseStatus {
-        match code {
+    /// Status with the `code`, `None` for codes without name
+    pub fn from_code(code: u16) -> Option<ResponseStatus> {
+        let status = match code {
             100 => Self::Continue,
             101 => Self::SwitchingProtocols,
             102 => Self::Processing,
@@ -124,10 +125,13 @@ impl ResponseStatus {
             508 => Self::LoopDetected,
             510 => Self::NotExtended,
             511 => Self::NetworkAuthenticationRequired,
-            code => panic!("Unexected status code: {}", code),
-        }
+            _ => return None,
+        };
+
+        Some(status)
     }
 
+    #[allow(dead_code)]
     pub fn to_code(&self) -> u16 {
         match self {
             Self::Continue => 100,
@@ -192,9 +196,11 @@ impl ResponseStatus {
             Self::NetworkAuthenticationRequired => 511,
         }
     }
+}
 
-    pub fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for ResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
             Self::Continue => "Continue",
             Self::SwitchingProtocols => "Switching Protocols",
             Self::Processing => "Processing",
@@ -255,7 +261,8 @@ impl ResponseStatus {
             Self::LoopDetected => "Loop Detected",
             Self::NotExtended => "Not Extended",
             Self::NetworkAuthenticationRequired => "Network Authentication Required",
-        }
-        .to_owned()
+        };
+
+        write!(f, "{}", name)
     }
 }
@@ -0,0 +1,28 @@
+//! # Resolver
+//!
+//! Loads documents referenced by url, `$ref: "https://example.com/models.yaml#/User"`
+//!
+//! swagg never goes to the network itself, remote references are resolved only
+//! when resolver is passed to [`Settings`](crate::Settings)
+
+use std::collections::HashMap;
+
+/// Loads content of the remote document (yaml or json)
+pub trait Resolver {
+    fn resolve(&self, url: &str) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+impl std::fmt::Debug for dyn Resolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Resolver")
+    }
+}
+
+/// In-memory documents, mostly useful in tests
+impl Resolver for HashMap<String, String> {
+    fn resolve(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.get(url)
+            .cloned()
+            .ok_or_else(|| format!("document {} is not found", url).into())
+    }
+}
@@ -0,0 +1,225 @@
+//! Generated code as a tree of module files
+//!
+//! Inline modules are moved to own files, `pub mod api { .. }` becomes `pub mod api;` and `api.rs`,
+//! modules with nested modules get directory with `mod.rs`:
+//!
+//! ```text
+//! mod.rs
+//! api.rs
+//! components/mod.rs
+//! components/schemas.rs
+//! paths/mod.rs
+//! paths/session_get.rs
+//! ```
+
+use crate::Error;
+use quote::ToTokens;
+use std::path::{Path, PathBuf};
+use syn::{AttrStyle, Item, ItemMod};
+
+/// Modules deeper than this are kept inline, `paths/session_get.rs` is the deepest file
+const MAX_DEPTH: usize = 2;
+
+/// Files of the module tree relative to output directory, root module is `mod.rs`
+pub(crate) fn split(file: syn::File, raw: bool) -> Vec<(PathBuf, String)> {
+    let mut files = vec![(PathBuf::from("mod.rs"), String::new())];
+    let items = split_items(file.items, Path::new(""), 1, raw, &mut files);
+
+    files[0].1 = print(file.attrs, items, raw);
+    files
+}
+
+/// Write files of the module tree into `dir`, returns paths of the written files
+///
+/// Directory belongs to the generated code: `.rs` files left from previous generation are removed,
+/// so modules of deleted operations and tags do not stay behind
+pub fn write_files(dir: &Path, files: &[(PathBuf, String)]) -> Result<Vec<PathBuf>, Error> {
+    let mut written = vec![];
+
+    for (path, content) in files {
+        let target = dir.join(path);
+
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|error| Error::Io(parent.to_path_buf(), error))?;
+        }
+        std::fs::write(&target, content).map_err(|error| Error::Io(target.clone(), error))?;
+
+        written.push(target);
+    }
+
+    remove_stale(dir, &written)?;
+
+    Ok(written)
+}
+
+/// Remove `.rs` files under `dir` that are not `written`, directories emptied by it are removed too,
+/// returns whether anything was removed
+fn remove_stale(dir: &Path, written: &[PathBuf]) -> Result<bool, Error> {
+    let io = |error| Error::Io(dir.to_path_buf(), error);
+    let mut removed = false;
+
+    for entry in std::fs::read_dir(dir).map_err(io)? {
+        let path = entry.map_err(io)?.path();
+
+        if path.is_dir() {
+            let emptied = remove_stale(&path, written)?;
+
+            if emptied && std::fs::read_dir(&path).map_err(io)?.next().is_none() {
+                std::fs::remove_dir(&path).map_err(|error| Error::Io(path.clone(), error))?;
+            }
+            removed |= emptied;
+        } else if path.extension().is_some_and(|ext| ext == "rs") && !written.contains(&path) {
+            std::fs::remove_file(&path).map_err(|error| Error::Io(path.clone(), error))?;
+            removed = true;
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Replace inline modules with declarations, their contents are added to `files` under `dir`
+fn split_items(
+    items: Vec<Item>,
+    dir: &Path,
+    depth: usize,
+    raw: bool,
+    files: &mut Vec<(PathBuf, String)>,
+) -> Vec<Item> {
+    items
+        .into_iter()
+        .map(|item| match item {
+            Item::Mod(module) if module.content.is_some() && depth <= MAX_DEPTH => {
+                split_module(module, dir, depth, raw, files)
+            }
+            item => item,
+        })
+        .collect()
+}
+
+fn split_module(
+    mut module: ItemMod,
+    dir: &Path,
+    depth: usize,
+    raw: bool,
+    files: &mut Vec<(PathBuf, String)>,
+) -> Item {
+    let (_, items) = module.content.take().unwrap_or_default();
+    let name = module.ident.to_string();
+    let name = name.trim_start_matches("r#");
+
+    let nested = depth < MAX_DEPTH
+        && items
+            .iter()
+            .any(|item| matches!(item, Item::Mod(child) if child.content.is_some()));
+
+    let (path, child_dir) = match nested {
+        true => (dir.join(name).join("mod.rs"), dir.join(name)),
+        false => (dir.join(format!("{}.rs", name)), dir.to_path_buf()),
+    };
+
+    // Parent file goes before files of its children
+    let index = files.len();
+    files.push((path, String::new()));
+
+    let items = split_items(items, &child_dir, depth + 1, raw, files);
+
+    // Inner attributes belong to the file, doc comments stay on the declaration
+    let (inner, outer) = module
+        .attrs
+        .into_iter()
+        .partition(|attr| matches!(attr.style, AttrStyle::Inner(_)));
+
+    files[index].1 = print(inner, items, raw);
+
+    module.attrs = outer;
+    module.semi = Some(Default::default());
+    Item::Mod(module)
+}
+
+fn print(attrs: Vec<syn::Attribute>, items: Vec<Item>, raw: bool) -> String {
+    let file = syn::File {
+        shebang: None,
+        attrs,
+        items,
+    };
+
+    match raw {
+        true => file.to_token_stream().to_string(),
+        false => prettyplease::unparse(&file),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::pretty;
+
+    #[test]
+    fn splits_modules_into_files() {
+        let file: syn::File = syn::parse_str(
+            r#"
+            #![allow(dead_code)]
+            pub mod api {
+                pub struct Api;
+            }
+            pub mod paths {
+                use super::components::responses;
+                /// Get session
+                pub mod session_get {
+                    pub mod headers {}
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let files = split(file, false);
+        let names: Vec<_> = files
+            .iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["mod.rs", "api.rs", "paths/mod.rs", "paths/session_get.rs"]
+        );
+        assert_eq!(
+            pretty(files[0].1.clone()),
+            "#![allow(dead_code)]\npub mod api;\npub mod paths;"
+        );
+        assert_eq!(
+            pretty(files[2].1.clone()),
+            "use super::components::responses;\n/// Get session\npub mod session_get;"
+        );
+        assert_eq!(pretty(files[3].1.clone()), "pub mod headers {}");
+    }
+
+    #[test]
+    fn removes_files_left_from_previous_generation() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = |path: &str| (PathBuf::from(path), String::new());
+
+        write_files(
+            dir.path(),
+            &[
+                file("mod.rs"),
+                file("paths/mod.rs"),
+                file("paths/session_get.rs"),
+                file("users/mod.rs"),
+            ],
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "").unwrap();
+
+        let written = write_files(dir.path(), &[file("mod.rs"), file("paths/mod.rs")]).unwrap();
+
+        assert_eq!(
+            written,
+            vec![dir.path().join("mod.rs"), dir.path().join("paths/mod.rs")]
+        );
+        assert!(!dir.path().join("paths/session_get.rs").exists());
+        assert!(!dir.path().join("users").exists());
+        assert!(dir.path().join("README.md").exists());
+    }
+}